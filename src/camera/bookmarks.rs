@@ -0,0 +1,85 @@
+use bevy::prelude::*;
+
+use super::{CameraCommand, MainCamera};
+use crate::input::{ActionInput, InputAction};
+
+/// Position et zoom enregistrés
+#[derive(Clone, Copy, Debug)]
+pub struct CameraBookmark {
+    pub position: Vec2,
+    pub scale: f32,
+}
+
+/// Retour aux signets, que d'autres plugins peuvent conditionner (l'éditeur
+/// réserve les chiffres au choix du terrain)
+#[derive(SystemSet, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct CameraBookmarkJump;
+
+/// Signets de caméra numérotés de 1 à 9
+#[derive(Resource, Default, Clone, Debug)]
+pub struct CameraBookmarks {
+    pub slots: [Option<CameraBookmark>; 9],
+}
+
+fn ctrl_pressed(keyboard: &ButtonInput<KeyCode>) -> bool {
+    keyboard.any_pressed([KeyCode::ControlLeft, KeyCode::ControlRight])
+}
+
+fn just_pressed_slot(input: &ActionInput) -> Option<usize> {
+    InputAction::BOOKMARKS
+        .iter()
+        .position(|action| input.just_pressed(*action))
+}
+
+/// Ctrl+1..9 (par défaut) enregistre la vue courante
+pub fn save_camera_bookmark(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    input: ActionInput,
+    mut bookmarks: ResMut<CameraBookmarks>,
+    camera: Query<(&Transform, &Projection), With<MainCamera>>,
+) {
+    if !ctrl_pressed(&keyboard) {
+        return;
+    }
+    let Some(slot) = just_pressed_slot(&input) else {
+        return;
+    };
+    let Ok((transform, projection)) = camera.single() else {
+        return;
+    };
+
+    let scale = match projection {
+        Projection::Orthographic(ortho) => ortho.scale,
+        _ => 1.0,
+    };
+    bookmarks.slots[slot] = Some(CameraBookmark {
+        position: transform.translation.truncate(),
+        scale,
+    });
+    info!("🔖 Signet {} enregistré", slot + 1);
+}
+
+/// 1..9 (sans Ctrl) retourne au signet
+pub fn jump_to_camera_bookmark(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    input: ActionInput,
+    bookmarks: Res<CameraBookmarks>,
+    mut camera_commands: MessageWriter<CameraCommand>,
+) {
+    if ctrl_pressed(&keyboard) {
+        return;
+    }
+    let Some(slot) = just_pressed_slot(&input) else {
+        return;
+    };
+
+    match bookmarks.slots[slot] {
+        Some(bookmark) => {
+            camera_commands.write(CameraCommand::FocusOn {
+                position: bookmark.position,
+                scale: Some(bookmark.scale),
+            });
+        }
+        None => info!("Signet {} vide", slot + 1),
+    }
+}
@@ -0,0 +1,91 @@
+use bevy::prelude::*;
+
+use super::{CameraMotion, CameraSettings, MainCamera};
+use crate::hex::HexConfig;
+use crate::hex::rendering::contour::systems::map_bounds;
+use crate::hex::rendering::contour::terrain::TerrainCells;
+
+/// Étendue monde de la carte générée, hors marge
+#[derive(Resource, Default, Clone, Copy, Debug)]
+pub struct CameraBounds(pub Option<Rect>);
+
+/// Recalcule l'étendue à partir des cellules générées ou chargées, dès
+/// qu'elles changent (nouvelle carte, régénération, chargement)
+pub fn update_camera_bounds(
+    cells: Option<Res<TerrainCells>>,
+    hex_config: Option<Res<HexConfig>>,
+    mut bounds: ResMut<CameraBounds>,
+) {
+    let (Some(cells), Some(hex_config)) = (cells, hex_config) else {
+        return;
+    };
+    if !cells.is_changed() && !hex_config.is_changed() {
+        return;
+    }
+    if cells.cells.is_empty() {
+        bounds.0 = None;
+        return;
+    }
+
+    let (min, max) = map_bounds(&hex_config.layout, cells.cells.coords());
+    bounds.0 = Some(Rect::from_corners(min, max));
+}
+
+/// Garde la vue dans les limites de la carte élargies de la marge : le
+/// dézoom est plafonné et le centre ramené à l'intérieur
+pub fn clamp_camera_to_bounds(
+    bounds: Res<CameraBounds>,
+    settings: Res<CameraSettings>,
+    mut camera: Query<
+        (&Camera, &mut Transform, &mut Projection, &mut CameraMotion),
+        With<MainCamera>,
+    >,
+) {
+    let Some(map) = bounds.0 else {
+        return;
+    };
+    let Ok((camera, mut transform, mut projection, mut motion)) = camera.single_mut() else {
+        return;
+    };
+    let Some(viewport) = camera.logical_viewport_size() else {
+        return;
+    };
+    let Projection::Orthographic(ortho) = projection.as_mut() else {
+        return;
+    };
+
+    let allowed = map.inflate(settings.bounds_margin);
+
+    // Dézoom maximal : la vue ne dépasse pas la zone autorisée
+    let fit_scale = (allowed.size() / viewport).min_element();
+    let max_scale = settings.max_zoom.min(fit_scale).max(settings.min_zoom);
+    if ortho.scale > max_scale {
+        ortho.scale = max_scale;
+    }
+    if let Some(target) = motion.target_scale.as_mut() {
+        *target = target.min(max_scale);
+    }
+
+    // Centre possible ; sur un axe plus petit que la vue, la carte est centrée
+    let half_view = viewport * ortho.scale * 0.5;
+    let low = allowed.min + half_view;
+    let high = allowed.max - half_view;
+    let center = allowed.center();
+    let position = transform.translation.truncate();
+    let clamped = Vec2::new(
+        if low.x <= high.x { position.x.clamp(low.x, high.x) } else { center.x },
+        if low.y <= high.y { position.y.clamp(low.y, high.y) } else { center.y },
+    );
+
+    if clamped != position {
+        // Arrêter l'inertie sur l'axe bloqué
+        if clamped.x != position.x {
+            motion.velocity.x = 0.0;
+        }
+        if clamped.y != position.y {
+            motion.velocity.y = 0.0;
+        }
+        transform.translation.x = clamped.x;
+        transform.translation.y = clamped.y;
+    }
+}
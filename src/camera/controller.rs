@@ -1,67 +1,202 @@
+use bevy::input::mouse::{MouseScrollUnit, MouseWheel};
 use bevy::prelude::*;
-use super::{MainCamera, CameraSettings};
+use bevy::window::PrimaryWindow;
+use super::{CameraMotion, CameraSettings, MainCamera};
+use crate::input::{ActionInput, InputAction, KeyModifier};
 
+// Échelle orthographique courante (1.0 pour une autre projection)
+fn projection_scale(projection: &Projection) -> f32 {
+    if let Projection::Orthographic(ortho) = projection {
+        ortho.scale
+    } else {
+        1.0
+    }
+}
+
+/// Actions Pan* (clavier, stick) et défilement par les bords de la fenêtre
 pub fn camera_movement(
-    time: Res<Time>,
-    keys: Res<ButtonInput<KeyCode>>,
+    input: ActionInput,
     settings: Res<CameraSettings>,
-    mut camera: Query<(&mut Transform, &Projection), With<MainCamera>>,
+    windows: Query<&Window, With<PrimaryWindow>>,
+    mut camera: Query<(&Projection, &mut CameraMotion), With<MainCamera>>,
 ) {
-    let Ok((mut transform, projection)) = camera.single_mut() else {
+    let Ok((projection, mut motion)) = camera.single_mut() else {
         return;
     };
+    // Ctrl+lettre est un raccourci (Ctrl+Z sur Z en AZERTY), pas un déplacement
+    if motion.dragging || input.modifier_pressed(KeyModifier::Control) {
+        return;
+    }
 
-    let scale = if let Projection::Orthographic(ortho) = projection {
-        ortho.scale
-    } else {
-        1.0
-    };
-
-    let speed = settings.speed * time.delta_secs() * scale;
+    // Un stick à mi-course donne la moitié de la vitesse
+    let mut direction = Vec2::new(
+        input.axis(InputAction::PanLeft, InputAction::PanRight),
+        input.axis(InputAction::PanDown, InputAction::PanUp),
+    );
 
-    if keys.pressed(KeyCode::KeyW) || keys.pressed(KeyCode::ArrowUp) {
-        transform.translation.y += speed;
-    }
-    if keys.pressed(KeyCode::KeyS) || keys.pressed(KeyCode::ArrowDown) {
-        transform.translation.y -= speed;
-    }
-    if keys.pressed(KeyCode::KeyA) || keys.pressed(KeyCode::ArrowLeft) {
-        transform.translation.x -= speed;
+    // Le bord de la fenêtre peut être couvert par un panneau egui (minimap)
+    if settings.edge_scroll
+        && !input.pointer_over_ui()
+        && let Some((window, cursor)) = windows
+            .single()
+            .ok()
+            .and_then(|window| Some((window, window.cursor_position()?)))
+    {
+        let margin = settings.edge_margin;
+        if cursor.x <= margin {
+            direction.x -= 1.0;
+        } else if cursor.x >= window.width() - margin {
+            direction.x += 1.0;
+        }
+        // Axe y de la fenêtre vers le bas
+        if cursor.y <= margin {
+            direction.y += 1.0;
+        } else if cursor.y >= window.height() - margin {
+            direction.y -= 1.0;
+        }
     }
-    if keys.pressed(KeyCode::KeyD) || keys.pressed(KeyCode::ArrowRight) {
-        transform.translation.x += speed;
+
+    if direction != Vec2::ZERO {
+        direction = direction.clamp_length_max(1.0);
+        motion.velocity = direction * settings.speed * projection_scale(projection);
     }
 }
 
+/// Glisser avec un bouton lié à `InputAction::DragPan` déplace la carte
+/// sous le curseur. La vitesse du geste est conservée au relâchement.
+pub fn camera_drag_pan(
+    time: Res<Time>,
+    input: ActionInput,
+    windows: Query<&Window, With<PrimaryWindow>>,
+    // Position du curseur à l'image précédente du glissé
+    mut last_cursor: Local<Option<Vec2>>,
+    mut camera: Query<
+        (&Camera, &GlobalTransform, &mut Transform, &mut CameraMotion),
+        With<MainCamera>,
+    >,
+) {
+    let Ok((camera, camera_transform, mut transform, mut motion)) = camera.single_mut() else {
+        return;
+    };
+
+    motion.dragging = input.pressed(InputAction::DragPan);
+    let cursor = windows.single().ok().and_then(Window::cursor_position);
+    let (Some(cursor), true) = (cursor, motion.dragging) else {
+        *last_cursor = None;
+        return;
+    };
+    let Some(previous) = last_cursor.replace(cursor) else {
+        return;
+    };
+
+    // Le point monde qui était sous le curseur doit y rester
+    let (Ok(from), Ok(to)) = (
+        camera.viewport_to_world_2d(camera_transform, previous),
+        camera.viewport_to_world_2d(camera_transform, cursor),
+    ) else {
+        return;
+    };
+    let delta = from - to;
+    transform.translation += delta.extend(0.0);
+
+    let dt = time.delta_secs();
+    motion.velocity = if dt > 0.0 { delta / dt } else { Vec2::ZERO };
+}
+
+/// Molette et actions ZoomIn/ZoomOut : zoom exponentiel vers une échelle visée
 pub fn camera_zoom(
-    mut scroll: MessageReader<bevy::input::mouse::MouseWheel>,
-    keys: Res<ButtonInput<KeyCode>>,
+    time: Res<Time>,
+    mut scroll: MessageReader<MouseWheel>,
+    input: ActionInput,
     settings: Res<CameraSettings>,
-    mut camera: Query<&mut Projection, With<MainCamera>>,
+    mut camera: Query<(&Projection, &mut CameraMotion), With<MainCamera>>,
 ) {
-    let Ok(mut projection) = camera.single_mut() else {
+    let Ok((projection, mut motion)) = camera.single_mut() else {
         return;
     };
 
-    if keys.pressed(KeyCode::NumpadAdd)
-    {
-        if let Projection::Orthographic(ortho) = projection.as_mut() {
-            ortho.scale -= 0.1;
-            ortho.scale = ortho.scale.clamp(settings.min_zoom, settings.max_zoom);
-        }
+    // En crans de molette, positifs pour zoomer. La molette sur un panneau
+    // egui ne zoome pas la carte.
+    let over_ui = input.pointer_over_ui();
+    let mut steps: f32 = scroll
+        .read()
+        .filter(|_| !over_ui)
+        .map(|event| match event.unit {
+            MouseScrollUnit::Line => event.y,
+            MouseScrollUnit::Pixel => event.y / PIXELS_PER_LINE,
+        })
+        .sum();
+    steps += input.axis(InputAction::ZoomOut, InputAction::ZoomIn)
+        * KEY_ZOOM_STEPS_PER_SECOND
+        * time.delta_secs();
+    if steps == 0.0 {
+        return;
     }
-    else if keys.pressed(KeyCode::NumpadSubtract)
-    {
-        if let Projection::Orthographic(ortho) = projection.as_mut() {
-            ortho.scale += 0.1;
-            ortho.scale = ortho.scale.clamp(settings.min_zoom, settings.max_zoom);
+
+    let current = motion.target_scale.unwrap_or(projection_scale(projection));
+    let factor = (1.0 + settings.zoom_step).powf(-steps * settings.zoom_speed);
+    motion.target_scale = Some((current * factor).clamp(settings.min_zoom, settings.max_zoom));
+}
+
+const PIXELS_PER_LINE: f32 = 100.0;
+const KEY_ZOOM_STEPS_PER_SECOND: f32 = 10.0;
+
+/// Intègre la vitesse (inertie) et rapproche l'échelle de sa cible en gardant
+/// fixe le point sous le curseur
+pub fn apply_camera_motion(
+    time: Res<Time>,
+    settings: Res<CameraSettings>,
+    windows: Query<&Window, With<PrimaryWindow>>,
+    mut camera: Query<
+        (&Camera, &mut Transform, &mut Projection, &mut CameraMotion),
+        With<MainCamera>,
+    >,
+) {
+    let Ok((camera, mut transform, mut projection, mut motion)) = camera.single_mut() else {
+        return;
+    };
+    let dt = time.delta_secs();
+
+    if !motion.dragging {
+        transform.translation += (motion.velocity * dt).extend(0.0);
+        motion.velocity *= if settings.inertia_damping > 0.0 {
+            (-settings.inertia_damping * dt).exp()
+        } else {
+            0.0
+        };
+        if motion.velocity.length_squared() < 1.0 {
+            motion.velocity = Vec2::ZERO;
         }
     }
 
-    for event in scroll.read() {
-        if let Projection::Orthographic(ortho) = projection.as_mut() {
-            ortho.scale -= event.y * settings.zoom_speed * 0.1;
-            ortho.scale = ortho.scale.clamp(settings.min_zoom, settings.max_zoom);
-        }
+    let Some(target) = motion.target_scale else {
+        return;
+    };
+    let Projection::Orthographic(ortho) = projection.as_mut() else {
+        motion.target_scale = None;
+        return;
+    };
+
+    let target = target.clamp(settings.min_zoom, settings.max_zoom);
+    let old_scale = ortho.scale;
+    let new_scale = if settings.zoom_smoothing > 0.0 && (target - old_scale).abs() > 1e-4 {
+        old_scale + (target - old_scale) * (1.0 - (-settings.zoom_smoothing * dt).exp())
+    } else {
+        motion.target_scale = None;
+        target
+    };
+    ortho.scale = new_scale;
+
+    // Décalage du curseur par rapport au centre de la vue, en pixels (y vers le haut)
+    let cursor_offset = settings
+        .zoom_to_cursor
+        .then(|| {
+            let cursor = windows.single().ok()?.cursor_position()?;
+            let center = camera.logical_viewport_rect()?.center();
+            Some(Vec2::new(cursor.x - center.x, center.y - cursor.y))
+        })
+        .flatten();
+    if let Some(offset) = cursor_offset {
+        transform.translation += (offset * (old_scale - new_scale)).extend(0.0);
     }
 }
@@ -0,0 +1,176 @@
+use bevy::ecs::system::SystemParam;
+use bevy::math::curve::{Curve, EaseFunction};
+use bevy::prelude::*;
+
+use super::{CameraMotion, CameraSettings, MainCamera};
+use crate::hex::{HexConfig, HexCoord};
+
+/// Demande de déplacement de la caméra (éditeur, gameplay, signets)
+#[derive(Message, Clone, Copy, Debug)]
+pub enum CameraCommand {
+    // Centre la vue sur une position monde, avec une échelle optionnelle
+    FocusOn { position: Vec2, scale: Option<f32> },
+    FocusOnHex(HexCoord),
+    Follow(Entity),
+    StopFollowing,
+}
+
+/// Accès pratique aux commandes de caméra depuis un système
+#[derive(SystemParam)]
+pub struct CameraControl<'w> {
+    commands: MessageWriter<'w, CameraCommand>,
+}
+
+impl CameraControl<'_> {
+    pub fn focus_on_hex(&mut self, coord: HexCoord) {
+        self.commands.write(CameraCommand::FocusOnHex(coord));
+    }
+
+    pub fn focus_on(&mut self, position: Vec2, scale: Option<f32>) {
+        self.commands.write(CameraCommand::FocusOn { position, scale });
+    }
+
+    pub fn follow(&mut self, entity: Entity) {
+        self.commands.write(CameraCommand::Follow(entity));
+    }
+
+    pub fn stop_following(&mut self) {
+        self.commands.write(CameraCommand::StopFollowing);
+    }
+}
+
+/// Animation vers un point et entité suivie
+#[derive(Component, Default)]
+pub struct CameraFocus {
+    pub animation: Option<FocusAnimation>,
+    pub follow: Option<Entity>,
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct FocusAnimation {
+    pub from: Vec2,
+    pub to: Vec2,
+    pub from_scale: f32,
+    pub to_scale: Option<f32>,
+    pub elapsed: f32,
+    pub duration: f32,
+}
+
+pub fn handle_camera_commands(
+    mut requests: MessageReader<CameraCommand>,
+    settings: Res<CameraSettings>,
+    hex_config: Option<Res<HexConfig>>,
+    mut camera: Query<
+        (&Transform, &Projection, &mut CameraMotion, &mut CameraFocus),
+        With<MainCamera>,
+    >,
+) {
+    let Ok((transform, projection, mut motion, mut focus)) = camera.single_mut() else {
+        requests.clear();
+        return;
+    };
+
+    for request in requests.read() {
+        let (position, scale) = match *request {
+            CameraCommand::FocusOn { position, scale } => (position, scale),
+            CameraCommand::FocusOnHex(coord) => {
+                let Some(hex_config) = hex_config.as_ref() else {
+                    continue;
+                };
+                (hex_config.layout.hex_to_world_pos(coord.to_hex()), None)
+            }
+            CameraCommand::Follow(entity) => {
+                focus.follow = Some(entity);
+                focus.animation = None;
+                continue;
+            }
+            CameraCommand::StopFollowing => {
+                focus.follow = None;
+                continue;
+            }
+        };
+
+        let from_scale = match projection {
+            Projection::Orthographic(ortho) => ortho.scale,
+            _ => 1.0,
+        };
+        focus.follow = None;
+        focus.animation = Some(FocusAnimation {
+            from: transform.translation.truncate(),
+            to: position,
+            from_scale,
+            to_scale: scale.map(|scale| scale.clamp(settings.min_zoom, settings.max_zoom)),
+            elapsed: 0.0,
+            duration: settings.focus_duration,
+        });
+        motion.velocity = Vec2::ZERO;
+        if scale.is_some() {
+            motion.target_scale = None;
+        }
+    }
+}
+
+/// Avance l'animation de focus ou suit l'entité ciblée. Un déplacement
+/// manuel (clavier, glisser) reprend la main.
+pub fn update_camera_focus(
+    time: Res<Time>,
+    settings: Res<CameraSettings>,
+    targets: Query<&GlobalTransform, Without<MainCamera>>,
+    mut camera: Query<
+        (&mut Transform, &mut Projection, &CameraMotion, &mut CameraFocus),
+        With<MainCamera>,
+    >,
+) {
+    let Ok((mut transform, mut projection, motion, mut focus)) = camera.single_mut() else {
+        return;
+    };
+
+    if motion.dragging || motion.velocity != Vec2::ZERO {
+        focus.animation = None;
+        focus.follow = None;
+        return;
+    }
+
+    if let Some(animation) = focus.animation.as_mut() {
+        animation.elapsed += time.delta_secs();
+        let progress = if animation.duration > 0.0 {
+            animation.elapsed / animation.duration
+        } else {
+            1.0
+        };
+        let t = EaseFunction::CubicInOut.sample_clamped(progress);
+
+        let position = animation.from.lerp(animation.to, t);
+        transform.translation.x = position.x;
+        transform.translation.y = position.y;
+        if let (Some(to_scale), Projection::Orthographic(ortho)) =
+            (animation.to_scale, projection.as_mut())
+        {
+            ortho.scale = animation.from_scale + (to_scale - animation.from_scale) * t;
+        }
+
+        if progress >= 1.0 {
+            focus.animation = None;
+        }
+        return;
+    }
+
+    let Some(entity) = focus.follow else {
+        return;
+    };
+    let Ok(target) = targets.get(entity) else {
+        // Entité despawnée
+        focus.follow = None;
+        return;
+    };
+
+    let target = target.translation().truncate();
+    let position = transform.translation.truncate();
+    let position = if settings.follow_smoothing > 0.0 {
+        position.lerp(target, 1.0 - (-settings.follow_smoothing * time.delta_secs()).exp())
+    } else {
+        target
+    };
+    transform.translation.x = position.x;
+    transform.translation.y = position.y;
+}
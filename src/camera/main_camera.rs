@@ -1,8 +1,33 @@
 use bevy::prelude::*;
 
+use super::CameraFocus;
+
 #[derive(Component)]
 pub struct MainCamera;
 
+/// État du déplacement de la caméra entre deux frames
+#[derive(Component, Default)]
+pub struct CameraMotion {
+    // Vitesse en unités monde par seconde (inertie)
+    pub velocity: Vec2,
+    // Échelle visée, atteinte progressivement
+    pub target_scale: Option<f32>,
+    pub dragging: bool,
+}
+
 pub fn setup_camera(mut commands: Commands) {
-    commands.spawn((Camera2d, MainCamera));
+    commands.spawn((
+        Camera2d,
+        MainCamera,
+        CameraMotion::default(),
+        CameraFocus::default(),
+    ));
+}
+
+/// Rectangle du monde visible par la caméra
+pub fn world_view_rect(camera: &Camera, transform: &GlobalTransform) -> Option<Rect> {
+    let viewport = camera.logical_viewport_rect()?;
+    let a = camera.viewport_to_world_2d(transform, viewport.min).ok()?;
+    let b = camera.viewport_to_world_2d(transform, viewport.max).ok()?;
+    Some(Rect::from_corners(a, b))
 }
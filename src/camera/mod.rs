@@ -1,8 +1,14 @@
+pub mod bookmarks;
+pub mod bounds;
 pub mod controller;
+pub mod focus;
 pub mod main_camera;
 pub mod plugin;
 pub mod settings;
 
-pub use main_camera::MainCamera;
+pub use bookmarks::{CameraBookmark, CameraBookmarkJump, CameraBookmarks};
+pub use bounds::CameraBounds;
+pub use focus::{CameraCommand, CameraControl, CameraFocus};
+pub use main_camera::{CameraMotion, MainCamera, world_view_rect};
 pub use plugin::CameraPlugin;
 pub use settings::CameraSettings;
@@ -1,8 +1,11 @@
 use bevy::prelude::*;
 
+use super::bookmarks;
+use super::bounds;
 use super::main_camera;
 use super::controller;
-use super::CameraSettings;
+use super::focus;
+use super::{CameraBookmarkJump, CameraBookmarks, CameraBounds, CameraCommand, CameraSettings};
 
 pub struct CameraPlugin;
 
@@ -10,7 +13,25 @@ impl Plugin for CameraPlugin {
     fn build(&self, app: &mut App) {
         app
             .init_resource::<CameraSettings>()
+            .init_resource::<CameraBounds>()
+            .init_resource::<CameraBookmarks>()
+            .add_message::<CameraCommand>()
             .add_systems(Startup, main_camera::setup_camera)
-            .add_systems(Update, (controller::camera_movement, controller::camera_zoom));
+            .add_systems(
+                Update,
+                (
+                    controller::camera_movement,
+                    controller::camera_drag_pan,
+                    controller::camera_zoom,
+                    bookmarks::save_camera_bookmark,
+                    bookmarks::jump_to_camera_bookmark.in_set(CameraBookmarkJump),
+                    focus::handle_camera_commands,
+                    focus::update_camera_focus,
+                    controller::apply_camera_motion,
+                    bounds::update_camera_bounds,
+                    bounds::clamp_camera_to_bounds,
+                )
+                    .chain(),
+            );
     }
 }
\ No newline at end of file
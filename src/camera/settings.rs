@@ -6,6 +6,23 @@ pub struct CameraSettings {
     pub zoom_speed: f32,
     pub min_zoom: f32,
     pub max_zoom: f32,
+    // Variation relative de l'échelle par cran de molette (zoom exponentiel)
+    pub zoom_step: f32,
+    // Le point sous le curseur reste fixe pendant le zoom
+    pub zoom_to_cursor: bool,
+    // Vitesse de convergence vers le zoom visé (1/s), 0 = instantané
+    pub zoom_smoothing: f32,
+    // Amortissement de la vitesse après un déplacement (1/s), 0 = arrêt immédiat
+    pub inertia_damping: f32,
+    pub edge_scroll: bool,
+    // Distance au bord de la fenêtre qui déclenche le défilement (pixels)
+    pub edge_margin: f32,
+    // Marge autour de la carte jusqu'où la vue peut aller (unités monde)
+    pub bounds_margin: f32,
+    // Durée de l'animation de focus (secondes)
+    pub focus_duration: f32,
+    // Vitesse de rattrapage de l'entité suivie (1/s), 0 = collée à la cible
+    pub follow_smoothing: f32,
 }
 
 impl Default for CameraSettings {
@@ -15,6 +32,15 @@ impl Default for CameraSettings {
             zoom_speed: 1.,
             min_zoom: 0.5,
             max_zoom: 5.,
+            zoom_step: 0.1,
+            zoom_to_cursor: true,
+            zoom_smoothing: 12.0,
+            inertia_damping: 6.0,
+            edge_scroll: false,
+            edge_margin: 8.0,
+            bounds_margin: 200.0,
+            focus_duration: 0.6,
+            follow_smoothing: 8.0,
         }
     }
-}
\ No newline at end of file
+}
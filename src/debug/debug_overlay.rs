@@ -0,0 +1,37 @@
+use bevy::prelude::*;
+
+/// Calques de debug dessinés par-dessus la carte
+#[derive(Resource, Clone, Debug)]
+pub struct DebugOverlay {
+    pub chunks: bool,
+    pub hex_outlines: bool,
+    pub coord_labels: bool,
+    pub contours: bool,
+    // Contours bruts du marching squares plutôt que lissés
+    pub raw_contours: bool,
+    pub marching_grid: bool,
+    // Zoom (échelle orthographique) au-delà duquel les étiquettes sont masquées
+    pub label_max_scale: f32,
+    // Au-delà, contours d'hexagones et grille ne sont plus dessinés
+    pub max_hex_outlines: usize,
+    pub max_grid_lines: usize,
+    // Une flèche tous les N segments de contour
+    pub arrow_stride: usize,
+}
+
+impl Default for DebugOverlay {
+    fn default() -> Self {
+        Self {
+            chunks: false,
+            hex_outlines: false,
+            coord_labels: false,
+            contours: false,
+            raw_contours: false,
+            marching_grid: false,
+            label_max_scale: 1.0,
+            max_hex_outlines: 20_000,
+            max_grid_lines: 400,
+            arrow_stride: 12,
+        }
+    }
+}
@@ -0,0 +1,6 @@
+pub mod debug_overlay;
+pub mod plugin;
+pub mod systems;
+
+pub use debug_overlay::DebugOverlay;
+pub use plugin::DebugOverlayPlugin;
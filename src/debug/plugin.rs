@@ -0,0 +1,29 @@
+use bevy::dev_tools::picking_debug::DebugPickingMode;
+use bevy::prelude::*;
+use bevy_inspector_egui::bevy_egui;
+
+use super::{DebugOverlay, systems};
+
+pub struct DebugOverlayPlugin;
+
+impl Plugin for DebugOverlayPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<DebugOverlay>()
+            .add_systems(
+                Update,
+                (
+                    systems::toggle_debug_overlays,
+                    systems::cycle_picking_debug.run_if(resource_exists::<DebugPickingMode>),
+                    (
+                        systems::draw_chunk_boundaries,
+                        systems::draw_hex_outlines,
+                        systems::draw_contours,
+                        systems::draw_marching_grid,
+                    )
+                        .run_if(resource_exists::<crate::hex::HexConfig>),
+                )
+                    .chain(),
+            )
+            .add_systems(bevy_egui::EguiPrimaryContextPass, systems::draw_coord_labels);
+    }
+}
@@ -0,0 +1,303 @@
+use bevy::color::palettes::tailwind::{
+    AMBER_400, CYAN_300, FUCHSIA_400, GRAY_500, LIME_400, ORANGE_400, SKY_400,
+};
+use bevy::dev_tools::picking_debug::DebugPickingMode;
+use bevy::prelude::*;
+use bevy_inspector_egui::bevy_egui::{self, egui};
+use hexx::Hex;
+
+use super::DebugOverlay;
+use crate::camera::{MainCamera, world_view_rect};
+use crate::hex::HexCoord;
+use crate::hex::rendering::config::HexConfig;
+use crate::hex::rendering::contour::systems::MarchingGrid;
+use crate::hex::rendering::contour::terrain::TerrainCells;
+use crate::hex::rendering::contour::{ContourPath, IsoContourKind, TerrainContours};
+use crate::input::{ActionInput, InputAction};
+use crate::shared::types::ChunkId;
+
+/// F1 chunks, F2 contours d'hexagones, F4 coordonnées, F8 contours
+/// (Maj+F8 bruts/lissés), F12 grille du marching squares (liaisons par défaut).
+/// F6 et F7 sont réservées aux exports.
+pub fn toggle_debug_overlays(input: ActionInput, mut overlay: ResMut<DebugOverlay>) {
+    let overlay = overlay.as_mut();
+
+    // Maj+F8 ne masque pas les contours en plus de changer leur version
+    if input.just_pressed(InputAction::ToggleRawContours) {
+        overlay.raw_contours = !overlay.raw_contours;
+        info!(
+            "🔧 Debug contours {}",
+            if overlay.raw_contours { "bruts" } else { "lissés" }
+        );
+        return;
+    }
+
+    let toggles: [(InputAction, &str, &mut bool); 5] = [
+        (InputAction::ToggleChunkDebug, "chunks", &mut overlay.chunks),
+        (InputAction::ToggleHexDebug, "hexagones", &mut overlay.hex_outlines),
+        (InputAction::ToggleCoordDebug, "coordonnées", &mut overlay.coord_labels),
+        (InputAction::ToggleContourDebug, "contours", &mut overlay.contours),
+        (
+            InputAction::ToggleMarchingGridDebug,
+            "grille marching squares",
+            &mut overlay.marching_grid,
+        ),
+    ];
+    for (action, name, enabled) in toggles {
+        if input.just_pressed(action) {
+            *enabled = !*enabled;
+            info!("🔧 Debug {} {}", name, if *enabled { "affiché" } else { "masqué" });
+        }
+    }
+}
+
+/// F3 (par défaut) : désactivé → normal → verbeux
+pub fn cycle_picking_debug(input: ActionInput, mut mode: ResMut<DebugPickingMode>) {
+    if !input.just_pressed(InputAction::CyclePickingDebug) {
+        return;
+    }
+    *mode = match *mode {
+        DebugPickingMode::Disabled => DebugPickingMode::Normal,
+        DebugPickingMode::Normal => DebugPickingMode::Noisy,
+        DebugPickingMode::Noisy => DebugPickingMode::Disabled,
+    };
+    info!("🔧 Debug picking: {:?}", *mode);
+}
+
+// Hexagones dont le centre est dans la vue (élargie d'un hexagone)
+fn hexes_in_view(hex_config: &HexConfig, view: Rect) -> impl Iterator<Item = Hex> + '_ {
+    let layout = &hex_config.layout;
+    let view = view.inflate(layout.scale.max_element());
+    let center = layout.world_pos_to_hex(view.center());
+    let radius = (view.half_size().length() / layout.scale.min_element()).ceil() as u32 + 1;
+
+    center
+        .range(radius)
+        .filter(move |hex| view.contains(layout.hex_to_world_pos(*hex)))
+}
+
+fn closed_outline(corners: [Vec2; 6]) -> impl Iterator<Item = Vec2> {
+    corners.into_iter().chain(std::iter::once(corners[0]))
+}
+
+/// Contours des chunks selon `HexConfig::chunk_layout`, avec le centre réel
+/// de chaque chunk (hexagone de plus haute résolution) pour vérifier l'alignement
+pub fn draw_chunk_boundaries(
+    overlay: Res<DebugOverlay>,
+    hex_config: Res<HexConfig>,
+    cameras: Query<(&Camera, &GlobalTransform), With<MainCamera>>,
+    mut gizmos: Gizmos,
+) {
+    if !overlay.chunks {
+        return;
+    }
+    let Ok((camera, camera_transform)) = cameras.single() else {
+        return;
+    };
+    let Some(view) = world_view_rect(camera, camera_transform) else {
+        return;
+    };
+
+    let layout = &hex_config.layout;
+    let chunk_size = hex_config.chunk_size;
+    let center = HexCoord::from_hex(layout.world_pos_to_hex(view.center()));
+    let chunk_extent = layout.scale.min_element() * chunk_size.max(1) as f32;
+    let radius = (view.half_size().length() / chunk_extent).ceil() as u32 + 1;
+
+    for chunk in ChunkId::from_hex_coord(center, chunk_size).range(radius) {
+        let chunk_hex = chunk.coord.to_hex();
+        gizmos.linestrip_2d(
+            closed_outline(hex_config.chunk_layout.hex_corners(chunk_hex)),
+            AMBER_400,
+        );
+
+        let chunk_center = layout.hex_to_world_pos(chunk_hex.to_higher_res(chunk_size as u32));
+        gizmos.circle_2d(chunk_center, hex_config.hex_radius * 0.3, ORANGE_400);
+    }
+}
+
+pub fn draw_hex_outlines(
+    overlay: Res<DebugOverlay>,
+    hex_config: Res<HexConfig>,
+    cameras: Query<(&Camera, &GlobalTransform), With<MainCamera>>,
+    mut gizmos: Gizmos,
+) {
+    if !overlay.hex_outlines {
+        return;
+    }
+    let Ok((camera, camera_transform)) = cameras.single() else {
+        return;
+    };
+    let Some(view) = world_view_rect(camera, camera_transform) else {
+        return;
+    };
+
+    let hexes: Vec<Hex> = hexes_in_view(&hex_config, view)
+        .take(overlay.max_hex_outlines + 1)
+        .collect();
+    if hexes.len() > overlay.max_hex_outlines {
+        return;
+    }
+
+    let color = GRAY_500.with_alpha(0.6);
+    for hex in hexes {
+        gizmos.linestrip_2d(closed_outline(hex_config.layout.hex_corners(hex)), color);
+    }
+}
+
+/// Étiquettes `q,r` des hexagones visibles, tant que le zoom le permet
+pub fn draw_coord_labels(
+    overlay: Res<DebugOverlay>,
+    hex_config: Option<Res<HexConfig>>,
+    cameras: Query<(&Camera, &GlobalTransform, &Projection), With<MainCamera>>,
+    mut contexts: Query<&mut bevy_egui::EguiContext>,
+) {
+    if !overlay.coord_labels {
+        return;
+    }
+    let Some(hex_config) = hex_config else {
+        return;
+    };
+    let Ok((camera, camera_transform, projection)) = cameras.single() else {
+        return;
+    };
+    if let Projection::Orthographic(ortho) = projection
+        && ortho.scale > overlay.label_max_scale
+    {
+        return;
+    }
+    let Some(view) = world_view_rect(camera, camera_transform) else {
+        return;
+    };
+    let Ok(mut ctx) = contexts.single_mut() else {
+        return;
+    };
+
+    let painter = ctx.get_mut().layer_painter(egui::LayerId::background());
+    let font = egui::FontId::monospace(10.0);
+    for hex in hexes_in_view(&hex_config, view).take(overlay.max_hex_outlines) {
+        let world_pos = hex_config.layout.hex_to_world_pos(hex);
+        let Ok(screen) = camera.world_to_viewport(camera_transform, world_pos.extend(0.0)) else {
+            continue;
+        };
+        painter.text(
+            egui::pos2(screen.x, screen.y),
+            egui::Align2::CENTER_CENTER,
+            format!("{},{}", hex.x, hex.y),
+            font.clone(),
+            egui::Color32::WHITE,
+        );
+    }
+}
+
+// Polyligne du contour, fermée si besoin, avec des flèches de sens de parcours
+fn draw_contour_path(
+    gizmos: &mut Gizmos,
+    path: &ContourPath,
+    color: Srgba,
+    arrow_length: f32,
+    arrow_stride: usize,
+) {
+    let points = &path.points;
+    if points.len() < 2 {
+        return;
+    }
+
+    let closing = path.is_closed.then(|| points[0]);
+    gizmos.linestrip_2d(points.iter().copied().chain(closing), color);
+
+    for segment in points.windows(2).step_by(arrow_stride.max(1)) {
+        let Some(direction) = (segment[1] - segment[0]).try_normalize() else {
+            continue;
+        };
+        gizmos
+            .arrow_2d(segment[0], segment[0] + direction * arrow_length, color)
+            .with_tip_length(arrow_length * 0.5);
+    }
+}
+
+pub fn draw_contours(
+    overlay: Res<DebugOverlay>,
+    hex_config: Res<HexConfig>,
+    contours: Option<Res<TerrainContours>>,
+    mut gizmos: Gizmos,
+) {
+    if !overlay.contours {
+        return;
+    }
+    let Some(contours) = contours else {
+        return;
+    };
+
+    let smoothed = !overlay.raw_contours;
+    let arrow_length = hex_config.hex_radius * 0.5;
+
+    for path in contours.coastline(smoothed) {
+        draw_contour_path(&mut gizmos, path, FUCHSIA_400, arrow_length, overlay.arrow_stride);
+    }
+    for iso in contours.iso_contours(smoothed) {
+        let color = match iso.kind {
+            IsoContourKind::Depth => SKY_400,
+            IsoContourKind::Elevation => LIME_400,
+        };
+        draw_contour_path(&mut gizmos, &iso.path, color, arrow_length, overlay.arrow_stride);
+    }
+}
+
+/// Grille du marching squares du trait de côte. Les lignes ne sont
+/// dessinées que lorsque la partie visible est assez petite.
+pub fn draw_marching_grid(
+    overlay: Res<DebugOverlay>,
+    hex_config: Res<HexConfig>,
+    cells: Option<Res<TerrainCells>>,
+    cameras: Query<(&Camera, &GlobalTransform), With<MainCamera>>,
+    mut grid: Local<Option<MarchingGrid>>,
+    mut gizmos: Gizmos,
+) {
+    if !overlay.marching_grid {
+        return;
+    }
+    let Some(cells) = cells else {
+        return;
+    };
+    if cells.is_changed() || grid.is_none() {
+        *grid = Some(MarchingGrid::new(&hex_config.layout, cells.cells.coords()));
+    }
+    let Some(grid) = *grid else {
+        return;
+    };
+
+    let bounds = Rect::from_corners(grid.min, grid.max);
+    gizmos.rect_2d(bounds.center(), bounds.size(), CYAN_300);
+
+    let Ok((camera, camera_transform)) = cameras.single() else {
+        return;
+    };
+    let Some(view) = world_view_rect(camera, camera_transform) else {
+        return;
+    };
+    let visible = view.intersect(bounds);
+    if visible.is_empty() {
+        return;
+    }
+
+    let cell_size = grid.cell_size();
+    let first = ((visible.min - grid.min) / cell_size).floor().max(Vec2::ZERO);
+    let last = ((visible.max - grid.min) / cell_size)
+        .ceil()
+        .min(Vec2::splat(grid.resolution as f32));
+    let line_count = (last - first + Vec2::ONE).element_sum() as usize;
+    if line_count > overlay.max_grid_lines {
+        return;
+    }
+
+    let color = CYAN_300.with_alpha(0.4);
+    for x in first.x as usize..=last.x as usize {
+        let px = grid.min.x + x as f32 * cell_size.x;
+        gizmos.line_2d(Vec2::new(px, visible.min.y), Vec2::new(px, visible.max.y), color);
+    }
+    for y in first.y as usize..=last.y as usize {
+        let py = grid.min.y + y as f32 * cell_size.y;
+        gizmos.line_2d(Vec2::new(visible.min.x, py), Vec2::new(visible.max.x, py), color);
+    }
+}
@@ -0,0 +1,170 @@
+use bevy::ecs::system::SystemParam;
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+
+use crate::hex;
+use crate::hex::HexCoord;
+use crate::hex::rendering::atlas::ColorTintMaterials;
+use crate::hex::rendering::components::HexTile;
+use crate::hex::rendering::config::HexConfig;
+use crate::hex::rendering::contour::terrain::TerrainCells;
+use crate::shared::types::{ChunkId, TerrainType};
+use crate::state::components::TerrainOverrides;
+
+/// Changement de terrain d'une cellule (`None` = terrain généré)
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub struct CellPaint {
+    pub coord: HexCoord,
+    pub before: Option<TerrainType>,
+    pub after: Option<TerrainType>,
+}
+
+/// Modification réversible de la carte
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub enum EditCommand {
+    PaintCells { cells: Vec<CellPaint> },
+    SpawnTiles { coords: Vec<HexCoord> },
+    DespawnTiles { coords: Vec<HexCoord> },
+    // Tuiles effectivement présentes dans le chunk au moment du despawn
+    DespawnChunk { chunk: ChunkId, coords: Vec<HexCoord> },
+}
+
+/// Demande d'exécution d'une commande, enregistrée dans l'historique
+#[derive(Message, Clone, Debug)]
+pub struct EditRequest {
+    pub command: EditCommand,
+    // Fusionne avec la commande précédente (coup de pinceau continu)
+    pub merge: bool,
+}
+
+impl EditRequest {
+    pub fn new(command: EditCommand) -> Self {
+        Self {
+            command,
+            merge: false,
+        }
+    }
+}
+
+/// Ressources nécessaires pour exécuter une commande
+#[derive(SystemParam)]
+pub struct EditContext<'w, 's> {
+    commands: Commands<'w, 's>,
+    overrides: ResMut<'w, TerrainOverrides>,
+    cells: Option<ResMut<'w, TerrainCells>>,
+    hex_config: Res<'w, HexConfig>,
+    color_tint_materials: Option<Res<'w, ColorTintMaterials>>,
+    tiles: Query<'w, 's, (Entity, &'static HexTile)>,
+}
+
+impl EditCommand {
+    /// Commande qui annule celle-ci
+    pub fn inverse(&self) -> EditCommand {
+        match self {
+            EditCommand::PaintCells { cells } => EditCommand::PaintCells {
+                cells: cells
+                    .iter()
+                    .rev()
+                    .map(|paint| CellPaint {
+                        coord: paint.coord,
+                        before: paint.after,
+                        after: paint.before,
+                    })
+                    .collect(),
+            },
+            EditCommand::SpawnTiles { coords } => EditCommand::DespawnTiles {
+                coords: coords.clone(),
+            },
+            EditCommand::DespawnTiles { coords } | EditCommand::DespawnChunk { coords, .. } => {
+                EditCommand::SpawnTiles {
+                    coords: coords.clone(),
+                }
+            }
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        match self {
+            EditCommand::PaintCells { cells } => cells.is_empty(),
+            EditCommand::SpawnTiles { coords }
+            | EditCommand::DespawnTiles { coords }
+            | EditCommand::DespawnChunk { coords, .. } => coords.is_empty(),
+        }
+    }
+
+    /// Ajoute les cellules d'une autre peinture. Le premier `before` connu
+    /// d'une cellule est conservé pour que l'annulation restaure l'état initial.
+    pub fn merge(&mut self, other: EditCommand) -> Result<(), EditCommand> {
+        match (self, other) {
+            (EditCommand::PaintCells { cells }, EditCommand::PaintCells { cells: others }) => {
+                let mut positions: HashMap<HexCoord, usize> = cells
+                    .iter()
+                    .enumerate()
+                    .map(|(index, cell)| (cell.coord, index))
+                    .collect();
+                for paint in others {
+                    match positions.get(&paint.coord) {
+                        Some(&index) => cells[index].after = paint.after,
+                        None => {
+                            positions.insert(paint.coord, cells.len());
+                            cells.push(paint);
+                        }
+                    }
+                }
+                Ok(())
+            }
+            (_, other) => Err(other),
+        }
+    }
+
+    pub fn apply(&self, context: &mut EditContext) {
+        match self {
+            EditCommand::PaintCells { cells } => {
+                for paint in cells {
+                    match paint.after {
+                        Some(terrain_type) => {
+                            context.overrides.set(paint.coord, terrain_type);
+                        }
+                        None => {
+                            context.overrides.clear(&paint.coord);
+                        }
+                    }
+                    if let Some(terrain_cells) = context.cells.as_mut() {
+                        terrain_cells.paint(&paint.coord, paint.after);
+                    }
+                }
+            }
+            EditCommand::SpawnTiles { coords } => {
+                let Some(color_tint_materials) = context.color_tint_materials.as_ref() else {
+                    warn!("Matériaux des tuiles non chargés, spawn ignoré");
+                    return;
+                };
+                let mut existing: HashSet<_> =
+                    context.tiles.iter().map(|(_, tile)| tile.coord).collect();
+
+                for coord in coords {
+                    // Tuile déjà présente ou coordonnée en double
+                    if !existing.insert(*coord) {
+                        continue;
+                    }
+                    hex::rendering::spawn_hex_sprite(
+                        &mut context.commands,
+                        *coord,
+                        context.hex_config.layout.hex_to_world_pos(coord.to_hex()),
+                        (**color_tint_materials).clone(),
+                        HashSet::new(),
+                    );
+                }
+            }
+            EditCommand::DespawnTiles { coords } | EditCommand::DespawnChunk { coords, .. } => {
+                let coords: HashSet<_> = coords.iter().collect();
+                for (entity, tile) in &context.tiles {
+                    if coords.contains(&tile.coord) {
+                        context.commands.entity(entity).despawn();
+                    }
+                }
+            }
+        }
+    }
+}
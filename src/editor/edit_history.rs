@@ -0,0 +1,254 @@
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::fs::{self, File};
+use std::io::{self, BufReader, BufWriter, Read, Write};
+use std::path::Path;
+
+use super::EditCommand;
+
+const MAGIC: &[u8; 8] = b"HEXEDITS";
+const SESSION_VERSION: u32 = 1;
+
+/// Piles d'annulation et de rétablissement des modifications de la carte
+#[derive(Resource, Clone, Debug, Serialize, Deserialize)]
+pub struct EditHistory {
+    undo: Vec<EditCommand>,
+    redo: Vec<EditCommand>,
+    // Nombre maximal de commandes annulables
+    pub max_len: usize,
+}
+
+impl Default for EditHistory {
+    fn default() -> Self {
+        Self {
+            undo: Vec::new(),
+            redo: Vec::new(),
+            max_len: 500,
+        }
+    }
+}
+
+impl EditHistory {
+    /// Enregistre une commande exécutée. Une nouvelle modification invalide
+    /// les commandes annulées.
+    pub fn push(&mut self, command: EditCommand, merge: bool) {
+        self.redo.clear();
+
+        let command = match (merge, self.undo.last_mut()) {
+            (true, Some(last)) => match last.merge(command) {
+                Ok(()) => return,
+                Err(command) => command,
+            },
+            _ => command,
+        };
+
+        self.undo.push(command);
+        if self.undo.len() > self.max_len {
+            let overflow = self.undo.len() - self.max_len;
+            self.undo.drain(..overflow);
+        }
+    }
+
+    /// Commande inverse de la dernière modification, à exécuter
+    pub fn undo(&mut self) -> Option<EditCommand> {
+        let command = self.undo.pop()?;
+        let inverse = command.inverse();
+        self.redo.push(command);
+        Some(inverse)
+    }
+
+    /// Dernière commande annulée, à exécuter de nouveau
+    pub fn redo(&mut self) -> Option<EditCommand> {
+        let command = self.redo.pop()?;
+        self.undo.push(command.clone());
+        Some(command)
+    }
+
+    /// Commandes de la session, dans l'ordre d'exécution
+    pub fn session(&self) -> &[EditCommand] {
+        &self.undo
+    }
+
+    pub fn clear(&mut self) {
+        self.undo.clear();
+        self.redo.clear();
+    }
+
+    /// Écrit les commandes de la session pour la rejouer plus tard
+    pub fn save_session(&self, path: &Path) -> io::Result<()> {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+
+        let mut writer = BufWriter::new(File::create(path)?);
+        writer.write_all(MAGIC)?;
+        writer.write_all(&SESSION_VERSION.to_le_bytes())?;
+        bincode::serialize_into(&mut writer, self.session()).map_err(io::Error::other)?;
+        writer.flush()
+    }
+
+    pub fn load_session(path: &Path) -> io::Result<Vec<EditCommand>> {
+        let mut reader = BufReader::new(File::open(path)?);
+
+        let mut magic = [0u8; 8];
+        let mut version = [0u8; 4];
+        reader.read_exact(&mut magic)?;
+        reader.read_exact(&mut version)?;
+
+        if &magic != MAGIC || u32::from_le_bytes(version) != SESSION_VERSION {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "session d'édition invalide ou d'une autre version",
+            ));
+        }
+
+        bincode::deserialize_from(reader).map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::{CellPaint, EditContext};
+    use super::*;
+    use crate::hex::HexCoord;
+    use crate::hex::rendering::config::HexConfig;
+    use crate::shared::types::TerrainType::{self, Beach, DeepWater, Land};
+    use crate::state::components::TerrainOverrides;
+    use bevy::ecs::system::RunSystemOnce;
+
+    fn paint(q: i32, before: Option<TerrainType>, after: Option<TerrainType>) -> EditCommand {
+        EditCommand::PaintCells {
+            cells: vec![CellPaint {
+                coord: HexCoord::new(q, 0),
+                before,
+                after,
+            }],
+        }
+    }
+
+    // Monde minimal et historique, comme apply_edit_requests et undo_redo
+    struct Editor {
+        world: World,
+        history: EditHistory,
+    }
+
+    impl Editor {
+        fn new() -> Self {
+            let mut world = World::new();
+            world.init_resource::<TerrainOverrides>();
+            world.init_resource::<HexConfig>();
+            Self {
+                world,
+                history: EditHistory::default(),
+            }
+        }
+
+        fn apply(&mut self, command: EditCommand) {
+            self.world
+                .run_system_once(move |mut context: EditContext| command.apply(&mut context))
+                .unwrap();
+        }
+
+        fn execute(&mut self, command: EditCommand, merge: bool) {
+            self.apply(command.clone());
+            self.history.push(command, merge);
+        }
+
+        fn undo(&mut self) -> bool {
+            let command = self.history.undo();
+            command.map(|command| self.apply(command)).is_some()
+        }
+
+        fn redo(&mut self) -> bool {
+            let command = self.history.redo();
+            command.map(|command| self.apply(command)).is_some()
+        }
+
+        fn overrides(&self) -> Vec<(HexCoord, TerrainType)> {
+            let mut cells: Vec<_> = self
+                .world
+                .resource::<TerrainOverrides>()
+                .cells
+                .iter()
+                .map(|(coord, terrain_type)| (*coord, *terrain_type))
+                .collect();
+            cells.sort_by_key(|(coord, _)| (coord.q, coord.r));
+            cells
+        }
+    }
+
+    #[test]
+    fn merged_stroke_keeps_first_before() {
+        let mut editor = Editor::new();
+        editor.execute(paint(0, None, Some(Beach)), false);
+        editor.execute(paint(0, Some(Beach), Some(Land)), true);
+        editor.execute(paint(1, None, Some(Beach)), true);
+
+        let [EditCommand::PaintCells { cells }] = editor.history.session() else {
+            panic!("le coup de pinceau doit former une seule commande");
+        };
+        assert_eq!(cells.len(), 2);
+        assert_eq!(cells[0].before, None);
+        assert_eq!(cells[0].after, Some(Land));
+
+        assert!(editor.undo());
+        assert!(editor.overrides().is_empty());
+    }
+
+    #[test]
+    fn undo_redo_round_trip() {
+        let mut editor = Editor::new();
+        editor.execute(paint(0, None, Some(Beach)), false);
+        editor.execute(paint(0, Some(Beach), Some(Land)), false);
+        editor.execute(paint(1, None, Some(DeepWater)), false);
+        let edited = editor.overrides();
+
+        for _ in 0..3 {
+            assert!(editor.undo());
+        }
+        assert!(editor.overrides().is_empty());
+        assert!(!editor.undo());
+
+        for _ in 0..3 {
+            assert!(editor.redo());
+        }
+        assert_eq!(editor.overrides(), edited);
+        assert!(!editor.redo());
+
+        // Une nouvelle modification invalide les commandes annulées
+        assert!(editor.undo());
+        editor.execute(paint(2, None, Some(Beach)), false);
+        assert!(!editor.redo());
+    }
+
+    #[test]
+    fn session_replay_round_trip() {
+        let mut editor = Editor::new();
+        editor.execute(paint(0, None, Some(Beach)), false);
+        editor.execute(paint(1, None, Some(Land)), true);
+        editor.execute(paint(0, Some(Beach), None), false);
+        editor.execute(paint(2, None, Some(DeepWater)), false);
+
+        let path = std::env::temp_dir().join(format!("hexedits-{}.bin", std::process::id()));
+        editor.history.save_session(&path).unwrap();
+        let commands = EditHistory::load_session(&path);
+        fs::remove_file(&path).unwrap();
+        let commands = commands.unwrap();
+        assert_eq!(commands.len(), 3);
+
+        let mut replayed = Editor::new();
+        for command in commands {
+            replayed.apply(command);
+        }
+        assert_eq!(replayed.overrides(), editor.overrides());
+    }
+
+    #[test]
+    fn load_session_rejects_other_files() {
+        let path = std::env::temp_dir().join(format!("not-hexedits-{}.bin", std::process::id()));
+        fs::write(&path, b"TERRAIN\0\x01\0\0\0").unwrap();
+        let result = EditHistory::load_session(&path);
+        fs::remove_file(&path).unwrap();
+        assert_eq!(result.unwrap_err().kind(), io::ErrorKind::InvalidData);
+    }
+}
@@ -0,0 +1,55 @@
+use bevy::prelude::*;
+
+use crate::hex::HexCoord;
+use crate::shared::types::TerrainType;
+
+/// Outil de peinture actif
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum EditorTool {
+    // Pinceau circulaire suivant le curseur
+    #[default]
+    Brush,
+    // Ligne entre deux clics
+    Line,
+    // Remplissage de la zone de même terrain
+    Fill,
+}
+
+#[derive(Resource, Debug)]
+pub struct EditorState {
+    pub enabled: bool,
+    pub tool: EditorTool,
+    pub terrain_type: TerrainType,
+    // Rayon du pinceau en hexagones (0 = une seule cellule)
+    pub radius: u32,
+    pub max_radius: u32,
+    // Nombre maximal de cellules modifiées par un remplissage
+    pub fill_limit: usize,
+    // Début de la ligne en attente du second clic
+    pub line_start: Option<HexCoord>,
+    // Dernier hexagone peint pendant le coup de pinceau en cours
+    pub last_hex: Option<HexCoord>,
+    // Le coup de pinceau en cours a déjà produit une commande
+    pub stroke_recorded: bool,
+}
+
+impl Default for EditorState {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            tool: EditorTool::default(),
+            terrain_type: TerrainType::Land,
+            radius: 1,
+            max_radius: 10,
+            fill_limit: 20_000,
+            line_start: None,
+            last_hex: None,
+            stroke_recorded: false,
+        }
+    }
+}
+
+/// Condition d'exécution : mode édition désactivé
+pub fn editor_disabled(editor: Res<EditorState>) -> bool {
+    !editor.enabled
+}
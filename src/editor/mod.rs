@@ -0,0 +1,15 @@
+// =============================================================================
+// EDITOR MODULE
+// =============================================================================
+
+pub mod edit_command;
+pub mod edit_history;
+pub mod editor_state;
+pub mod plugin;
+pub mod systems;
+pub mod tools;
+
+pub use edit_command::{CellPaint, EditCommand, EditContext, EditRequest};
+pub use edit_history::EditHistory;
+pub use editor_state::{EditorState, EditorTool, editor_disabled};
+pub use plugin::EditorPlugin;
@@ -0,0 +1,35 @@
+// =============================================================================
+// Editor - Plugin
+// =============================================================================
+
+use bevy::prelude::*;
+
+use super::{EditHistory, EditRequest, EditorState, editor_disabled};
+use super::systems;
+use crate::camera::CameraBookmarkJump;
+use crate::state::components::TerrainOverrides;
+
+pub struct EditorPlugin;
+
+impl Plugin for EditorPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<EditorState>()
+            .init_resource::<EditHistory>()
+            .init_resource::<TerrainOverrides>()
+            .add_message::<EditRequest>()
+            // En mode édition, les chiffres choisissent le terrain
+            .configure_sets(Update, CameraBookmarkJump.run_if(editor_disabled))
+            .add_systems(
+                Update,
+                (
+                    systems::editor_shortcuts,
+                    systems::paint_terrain,
+                    systems::edit_session_on_key,
+                    systems::apply_edit_requests,
+                    systems::undo_redo,
+                    systems::sync_tile_terrain,
+                )
+                    .chain(),
+            );
+    }
+}
@@ -0,0 +1,272 @@
+use bevy::prelude::*;
+
+use std::path::Path;
+
+use super::tools;
+use super::{CellPaint, EditCommand, EditContext, EditHistory, EditRequest, EditorState, EditorTool};
+use crate::hex::HexCoord;
+use crate::hex::input::CursorHex;
+use crate::hex::rendering::atlas::ColorTintMaterials;
+use crate::hex::rendering::components::{HexTerrain, HexTile};
+use crate::hex::rendering::contour::terrain::TerrainCells;
+use crate::input::{ActionInput, InputAction};
+use crate::shared::types::TerrainType;
+use crate::state::components::TerrainOverrides;
+
+/// Raccourcis du mode édition (liaisons par défaut) : E active/désactive,
+/// B/L/F choisissent l'outil, 1-5 le terrain et [ ] le rayon du pinceau
+pub fn editor_shortcuts(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    input: ActionInput,
+    mut editor: ResMut<EditorState>,
+) {
+    if input.just_pressed(InputAction::ToggleEditor) {
+        editor.enabled = !editor.enabled;
+        editor.line_start = None;
+        editor.last_hex = None;
+        info!(
+            "✏️ Mode édition {}",
+            if editor.enabled { "activé" } else { "désactivé" }
+        );
+    }
+
+    if !editor.enabled {
+        return;
+    }
+
+    let tool = [
+        (InputAction::BrushTool, EditorTool::Brush),
+        (InputAction::LineTool, EditorTool::Line),
+        (InputAction::FillTool, EditorTool::Fill),
+    ]
+    .into_iter()
+    .find(|(action, _)| input.just_pressed(*action));
+    if let Some((_, tool)) = tool {
+        editor.tool = tool;
+        editor.line_start = None;
+        info!("Outil: {:?}", tool);
+    }
+
+    // Ctrl+chiffre enregistre un signet de caméra
+    let ctrl = keyboard.any_pressed([KeyCode::ControlLeft, KeyCode::ControlRight]);
+    for (action, terrain_type) in InputAction::TERRAINS.into_iter().zip(TerrainType::ALL) {
+        if !ctrl && input.just_pressed(action) {
+            editor.terrain_type = terrain_type;
+            info!("Terrain: {:?}", terrain_type);
+        }
+    }
+
+    if input.just_pressed(InputAction::BrushSmaller) {
+        editor.radius = editor.radius.saturating_sub(1);
+        info!("Rayon du pinceau: {}", editor.radius);
+    }
+    if input.just_pressed(InputAction::BrushLarger) {
+        editor.radius = (editor.radius + 1).min(editor.max_radius);
+        info!("Rayon du pinceau: {}", editor.radius);
+    }
+    if input.just_pressed(InputAction::Cancel) {
+        editor.line_start = None;
+    }
+}
+
+/// Applique l'outil actif sous le curseur
+pub fn paint_terrain(
+    mut editor: ResMut<EditorState>,
+    input: ActionInput,
+    cursor: CursorHex,
+    overrides: Res<TerrainOverrides>,
+    cells: Option<Res<TerrainCells>>,
+    mut edits: MessageWriter<EditRequest>,
+) -> Result {
+    if !editor.enabled {
+        return Ok(());
+    }
+
+    if !input.pressed(InputAction::Select) {
+        editor.last_hex = None;
+        editor.stroke_recorded = false;
+        return Ok(());
+    }
+
+    let Some(hex_coord) = cursor.hex()? else {
+        return Ok(());
+    };
+    // Terrain peint, sinon généré
+    let terrain_at = |coord: &HexCoord| {
+        overrides
+            .get(coord)
+            .or_else(|| cells.as_ref().and_then(|cells| cells.terrain_type(coord)))
+    };
+
+    let coords = match editor.tool {
+        EditorTool::Brush => {
+            if editor.last_hex == Some(hex_coord) {
+                return Ok(());
+            }
+            let coords = tools::brush_cells(editor.last_hex, hex_coord, editor.radius);
+            editor.last_hex = Some(hex_coord);
+            coords
+        }
+        EditorTool::Line => {
+            if !input.just_pressed(InputAction::Select) {
+                return Ok(());
+            }
+            match editor.line_start.take() {
+                Some(start) => tools::line_cells(start, hex_coord, editor.radius),
+                None => {
+                    editor.line_start = Some(hex_coord);
+                    return Ok(());
+                }
+            }
+        }
+        EditorTool::Fill => {
+            if !input.just_pressed(InputAction::Select) {
+                return Ok(());
+            }
+            let target = terrain_at(&hex_coord);
+            if target.is_none() || target == Some(editor.terrain_type) {
+                return Ok(());
+            }
+            tools::flood_fill(hex_coord, editor.fill_limit, |coord| {
+                terrain_at(coord) == target
+            })
+        }
+    };
+
+    // Cellules de la carte qui n'ont pas déjà ce terrain, peint ou généré :
+    // une modification sans effet désactiverait le cache du terrain.
+    // Sans terrain généré, toutes les cellules sont acceptées.
+    let terrain_type = editor.terrain_type;
+    let painted: Vec<CellPaint> = coords
+        .into_iter()
+        .filter(|coord| cells.as_ref().is_none_or(|cells| cells.contains(coord)))
+        .filter(|coord| terrain_at(coord) != Some(terrain_type))
+        .map(|coord| CellPaint {
+            coord,
+            before: overrides.get(&coord),
+            after: Some(terrain_type),
+        })
+        .collect();
+
+    if painted.is_empty() {
+        return Ok(());
+    }
+    if editor.tool != EditorTool::Brush {
+        info!("{} cellules peintes en {:?}", painted.len(), terrain_type);
+    }
+
+    // Un coup de pinceau continu forme une seule commande annulable
+    let merge = editor.tool == EditorTool::Brush && editor.stroke_recorded;
+    editor.stroke_recorded = true;
+    edits.write(EditRequest {
+        command: EditCommand::PaintCells { cells: painted },
+        merge,
+    });
+
+    Ok(())
+}
+
+/// Exécute les modifications demandées et les enregistre dans l'historique
+pub fn apply_edit_requests(
+    mut requests: MessageReader<EditRequest>,
+    mut history: ResMut<EditHistory>,
+    mut context: EditContext,
+) {
+    for request in requests.read() {
+        if request.command.is_empty() {
+            continue;
+        }
+        request.command.apply(&mut context);
+        history.push(request.command.clone(), request.merge);
+    }
+}
+
+/// Ctrl+Z annule, Ctrl+Y (ou Ctrl+Maj+Z) rétablit (liaisons par défaut)
+pub fn undo_redo(input: ActionInput, mut history: ResMut<EditHistory>, mut context: EditContext) {
+    // Ctrl+Maj+Z déclenche aussi Ctrl+Z : le rétablissement l'emporte
+    let command = if input.just_pressed(InputAction::Redo) {
+        history.redo()
+    } else if input.just_pressed(InputAction::Undo) {
+        history.undo()
+    } else {
+        return;
+    };
+
+    match command {
+        Some(command) => command.apply(&mut context),
+        None => info!("Rien à annuler ou rétablir"),
+    }
+}
+
+/// F10 (par défaut) : enregistre la session d'édition, Maj+F10 la rejoue
+pub fn edit_session_on_key(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    input: ActionInput,
+    history: Res<EditHistory>,
+    mut edits: MessageWriter<EditRequest>,
+) {
+    if !input.just_pressed(InputAction::EditSession) {
+        return;
+    }
+
+    let path = Path::new(EDIT_SESSION_PATH);
+    if keyboard.any_pressed([KeyCode::ShiftLeft, KeyCode::ShiftRight]) {
+        match EditHistory::load_session(path) {
+            Ok(commands) => {
+                info!("▶ Relecture de {} modifications ({})", commands.len(), path.display());
+                edits.write_batch(commands.into_iter().map(EditRequest::new));
+            }
+            Err(err) => error!("Échec du chargement de {} : {}", path.display(), err),
+        }
+    } else {
+        match history.save_session(path) {
+            Ok(()) => info!(
+                "💾 Session d'édition enregistrée ({} modifications, {})",
+                history.session().len(),
+                path.display()
+            ),
+            Err(err) => error!("Échec de l'enregistrement de {} : {}", path.display(), err),
+        }
+    }
+}
+
+const EDIT_SESSION_PATH: &str = "saves/session.edits";
+
+/// Met à jour le matériau des tuiles peintes (et des tuiles nouvellement spawnées)
+pub fn sync_tile_terrain(
+    mut commands: Commands,
+    overrides: Res<TerrainOverrides>,
+    color_tint_materials: Option<Res<ColorTintMaterials>>,
+    added: Query<(), Added<HexTile>>,
+    mut tiles: Query<(
+        Entity,
+        &HexTile,
+        Option<&HexTerrain>,
+        &mut MeshMaterial2d<ColorMaterial>,
+    )>,
+) {
+    let Some(color_tint_materials) = color_tint_materials else {
+        return;
+    };
+    if !overrides.is_changed() && added.is_empty() {
+        return;
+    }
+
+    for (entity, tile, current, mut material) in &mut tiles {
+        let painted = overrides.get(&tile.coord);
+        if painted == current.map(|terrain| terrain.0) {
+            continue;
+        }
+
+        match painted {
+            Some(terrain_type) => {
+                commands.entity(entity).insert(HexTerrain(terrain_type));
+                material.0 = color_tint_materials.terrain_material(terrain_type);
+            }
+            None => {
+                commands.entity(entity).remove::<HexTerrain>();
+                material.0 = color_tint_materials.get_material("default".to_string());
+            }
+        }
+    }
+}
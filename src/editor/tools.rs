@@ -0,0 +1,58 @@
+use std::collections::{HashSet, VecDeque};
+
+use crate::hex::HexCoord;
+
+/// Cellules couvertes par le pinceau le long d'un trait. Les hexagones
+/// intermédiaires évitent les trous quand le curseur se déplace vite.
+pub fn brush_cells(from: Option<HexCoord>, to: HexCoord, radius: u32) -> Vec<HexCoord> {
+    let stroke = match from {
+        Some(from) => from.line_to(&to),
+        None => vec![to],
+    };
+    thick_line(&stroke, radius)
+}
+
+/// Ligne entre deux hexagones, épaissie du rayon du pinceau
+pub fn line_cells(from: HexCoord, to: HexCoord, radius: u32) -> Vec<HexCoord> {
+    thick_line(&from.line_to(&to), radius)
+}
+
+fn thick_line(stroke: &[HexCoord], radius: u32) -> Vec<HexCoord> {
+    let mut seen = HashSet::new();
+    stroke
+        .iter()
+        .flat_map(|coord| coord.range(radius))
+        .filter(|coord| seen.insert(*coord))
+        .collect()
+}
+
+/// Remplissage par diffusion : toutes les cellules connexes à `start` pour
+/// lesquelles `same_region` est vrai, dans la limite de `limit` cellules
+pub fn flood_fill(
+    start: HexCoord,
+    limit: usize,
+    same_region: impl Fn(&HexCoord) -> bool,
+) -> Vec<HexCoord> {
+    if !same_region(&start) {
+        return Vec::new();
+    }
+
+    let mut visited = HashSet::from([start]);
+    let mut queue = VecDeque::from([start]);
+    let mut region = Vec::new();
+
+    while let Some(coord) = queue.pop_front() {
+        region.push(coord);
+        if region.len() >= limit {
+            break;
+        }
+
+        for neighbor in coord.neighbors() {
+            if same_region(&neighbor) && visited.insert(neighbor) {
+                queue.push_back(neighbor);
+            }
+        }
+    }
+
+    region
+}
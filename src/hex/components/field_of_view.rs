@@ -0,0 +1,196 @@
+use bevy::prelude::*;
+use hexx::Hex;
+use std::collections::HashSet;
+
+use super::hex_coord::HexCoord;
+use super::hex_map::HexMap;
+use crate::shared::types::TerrainType;
+
+/// Terrains qui bloquent la vue
+#[derive(Resource, Clone, Debug)]
+pub struct VisionConfig {
+    pub blocking: HashSet<TerrainType>,
+}
+
+impl Default for VisionConfig {
+    fn default() -> Self {
+        Self {
+            blocking: HashSet::from([TerrainType::Cliff]),
+        }
+    }
+}
+
+impl VisionConfig {
+    pub fn blocks(&self, terrain_type: TerrainType) -> bool {
+        self.blocking.contains(&terrain_type)
+    }
+}
+
+// Décalage des lignes de visée, en coordonnées axiales : trop petit pour
+// changer une cellule traversée franchement, il départage les lignes qui
+// longent une arête. (1, 2) n'est parallèle à aucune arête.
+const NUDGE: Vec2 = Vec2::new(1e-4, 2e-4);
+
+impl HexCoord {
+    /// Vrai si aucune cellule strictement entre `self` et `target` ne bloque.
+    /// La cellule cible elle-même peut être bloquante (on voit le mur).
+    /// La ligne est tracée décalée d'un côté puis de l'autre, et la vue passe
+    /// si l'un des deux tracés est dégagé : une ligne qui longe l'arête de
+    /// deux cellules bloquantes est arrêtée, et la relation est symétrique.
+    pub fn has_line_of_sight(
+        &self,
+        target: &HexCoord,
+        is_blocking: impl Fn(&HexCoord) -> bool,
+    ) -> bool {
+        // Même tracé quel que soit le sens
+        let (from, to) = if (self.q, self.r) <= (target.q, target.r) {
+            (self, target)
+        } else {
+            (target, self)
+        };
+        [NUDGE, -NUDGE]
+            .into_iter()
+            .any(|nudge| from.inner_line(to, nudge).all(|coord| !is_blocking(&coord)))
+    }
+
+    /// Visibilité des cellules du disque de rayon `range` autour de `self`,
+    /// d'après `has_line_of_sight` : une cellule voit une autre si et
+    /// seulement si l'autre la voit. Coût proportionnel au nombre de cellules
+    /// du disque multiplié par `range`.
+    pub fn field_of_view(
+        &self,
+        range: u32,
+        is_blocking: impl Fn(&HexCoord) -> bool,
+    ) -> HexMap<bool> {
+        // Chaque cellule n'est interrogée qu'une fois
+        let blocking = HexMap::hexagon(*self, range, |coord| is_blocking(&coord));
+        let is_blocking = |coord: &HexCoord| {
+            blocking
+                .get(coord)
+                .copied()
+                .unwrap_or_else(|| is_blocking(coord))
+        };
+
+        blocking.map(|coord, _| self.has_line_of_sight(&coord, is_blocking))
+    }
+
+    // Cellules strictement entre `self` et `target` sur la ligne décalée
+    fn inner_line(&self, target: &HexCoord, nudge: Vec2) -> impl Iterator<Item = HexCoord> {
+        let distance = self.distance(target);
+        let start = Vec2::new(self.q as f32, self.r as f32) + nudge;
+        let end = Vec2::new(target.q as f32, target.r as f32) + nudge;
+
+        (1..distance).map(move |step| {
+            let point = start.lerp(end, step as f32 / distance as f32);
+            HexCoord::from_hex(Hex::round(point.to_array()))
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn hex(q: i32, r: i32) -> HexCoord {
+        HexCoord::new(q, r)
+    }
+
+    // Terre ferme avec des falaises aux coordonnées données
+    fn terrain(cliffs: &[HexCoord]) -> HexMap<TerrainType> {
+        HexMap::hexagon(hex(0, 0), 12, |coord| {
+            if cliffs.contains(&coord) {
+                TerrainType::Cliff
+            } else {
+                TerrainType::Land
+            }
+        })
+    }
+
+    fn field_of_view(terrain: &HexMap<TerrainType>, from: HexCoord, range: u32) -> HexMap<bool> {
+        let vision = VisionConfig::default();
+        from.field_of_view(range, |coord| {
+            terrain
+                .get(coord)
+                .is_some_and(|terrain_type| vision.blocks(*terrain_type))
+        })
+    }
+
+    #[test]
+    fn open_ground_is_visible_up_to_range() {
+        let visible = field_of_view(&terrain(&[]), hex(1, -1), 4);
+        assert_eq!(visible.len(), 1 + 3 * 4 * 5);
+        assert!(visible.values().all(|visible| *visible));
+        assert!(visible.contains(&hex(5, -1)));
+        assert!(!visible.contains(&hex(6, -1)));
+    }
+
+    #[test]
+    fn cliff_wall_hides_cells_behind_it() {
+        let wall: Vec<_> = (-12..=12).map(|r| hex(2, r)).collect();
+        let visible = field_of_view(&terrain(&wall), hex(0, 0), 6);
+
+        for (coord, visible) in visible.iter() {
+            if coord.q != 2 {
+                assert_eq!(*visible, coord.q < 2, "{:?}", coord);
+            }
+        }
+        // Le mur est vu en face, mais masqué par lui-même en enfilade
+        assert!(visible[hex(2, -1)] && visible[hex(2, 0)] && visible[hex(2, 1)]);
+        assert!(!visible[hex(2, -6)]);
+    }
+
+    #[test]
+    fn gap_in_wall_lets_sight_through() {
+        let wall: Vec<_> = (-12..=12).filter(|r| *r != 0).map(|r| hex(2, r)).collect();
+        let visible = field_of_view(&terrain(&wall), hex(0, 0), 6);
+
+        assert!(visible[hex(3, 0)]);
+        assert!(visible[hex(6, 0)]);
+        assert!(!visible[hex(3, -3)]);
+        assert!(!visible[hex(3, 2)]);
+    }
+
+    #[test]
+    fn single_cliff_shadows_only_straight_behind() {
+        let visible = field_of_view(&terrain(&[hex(1, 0)]), hex(0, 0), 4);
+
+        assert!(visible[hex(1, 0)]);
+        assert!(!visible[hex(2, 0)]);
+        assert!(!visible[hex(4, 0)]);
+        // Lignes qui longent l'arête de la falaise : l'autre côté est dégagé
+        assert!(visible[hex(2, -1)]);
+        assert!(visible[hex(1, 1)]);
+    }
+
+    #[test]
+    fn vision_is_symmetric() {
+        // Falaises pseudo-aléatoires mais reproductibles
+        let mut seed = 0x2545_f491_u32;
+        let cliffs: Vec<_> = hex(0, 0)
+            .range(6)
+            .into_iter()
+            .filter(|_| {
+                seed ^= seed << 13;
+                seed ^= seed >> 17;
+                seed ^= seed << 5;
+                seed.is_multiple_of(4)
+            })
+            .collect();
+        let terrain = terrain(&cliffs);
+
+        let range = 5;
+        let viewers = hex(0, 0).range(4);
+        let views: Vec<_> = viewers
+            .iter()
+            .map(|viewer| field_of_view(&terrain, *viewer, range))
+            .collect();
+
+        for (a, view_a) in viewers.iter().zip(&views) {
+            for (b, view_b) in viewers.iter().zip(&views) {
+                if a.distance(b) <= range {
+                    assert_eq!(view_a[*b], view_b[*a], "{:?} ↔ {:?}", a, b);
+                }
+            }
+        }
+    }
+}
@@ -1,7 +1,8 @@
 use bevy::prelude::*;
 use hexx::Hex;
+use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct HexCoord {
     pub q: i32,
     pub r: i32,
@@ -60,12 +61,12 @@ impl HexCoord {
 
     /// Retourne un anneau d'hexagones à une distance donnée
     pub fn rings(&self, range: impl Iterator<Item = u32>) -> Vec<HexCoord> {
-        self.to_hex().rings(range).into_iter().flatten().map(HexCoord::from_hex).collect()
+        self.to_hex().rings(range).flatten().map(HexCoord::from_hex).collect()
     }
     
     /// Retourne un anneau d'hexagones à une distance donnée
     pub fn spiral(&self, range: impl Iterator<Item = u32>) -> Vec<HexCoord> {
         
-        self.to_hex().rings(range).into_iter().flatten().map(HexCoord::from_hex).collect()
+        self.to_hex().rings(range).flatten().map(HexCoord::from_hex).collect()
     }
 }
@@ -0,0 +1,415 @@
+use hexx::storage::HexStore;
+use hexx::{Hex, HexOrientation, shapes};
+use rayon::prelude::*;
+use serde::de::Error as _;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::ops::{Index, IndexMut};
+
+use super::hex_coord::HexCoord;
+
+// Ligne r de la grille : cellules q_min..q_min+len, rangées à partir de `start`
+#[derive(Clone, Copy, Debug)]
+struct Row {
+    q_min: i32,
+    len: u32,
+    start: usize,
+}
+
+/// Stockage dense de données par cellule, indexé par coordonnées axiales.
+/// Les cellules sont rangées ligne par ligne (r croissant, puis q), ce qui
+/// donne un accès O(1) sans hachage et des parcours contigus en mémoire.
+/// Toute forme dont chaque ligne est un intervalle de q est supportée
+/// (hexagone, rectangle, losange). Implémente `hexx::storage::HexStore`
+/// comme les stockages denses de hexx.
+#[derive(Clone, Debug)]
+pub struct HexMap<T> {
+    r_min: i32,
+    rows: Vec<Row>,
+    coords: Vec<HexCoord>,
+    values: Vec<T>,
+}
+
+impl<T> Default for HexMap<T> {
+    fn default() -> Self {
+        Self {
+            r_min: 0,
+            rows: Vec::new(),
+            coords: Vec::new(),
+            values: Vec::new(),
+        }
+    }
+}
+
+impl<T> HexMap<T> {
+    /// Hexagone de rayon `radius` autour de `center` (`hexx::shapes::hexagon`)
+    pub fn hexagon(center: HexCoord, radius: u32, f: impl FnMut(HexCoord) -> T) -> Self {
+        Self::from_shape(shapes::hexagon(center.to_hex(), radius), f)
+    }
+
+    /// Rectangle de `columns` x `rows` cellules en coordonnées décalées,
+    /// coin en (0, 0), selon `hexx::shapes::flat_rectangle` ou
+    /// `pointy_rectangle`. Les colonnes suivent q en orientation Flat, les
+    /// lignes suivent r en orientation Pointy.
+    pub fn rectangle(
+        orientation: HexOrientation,
+        columns: u32,
+        rows: u32,
+        f: impl FnMut(HexCoord) -> T,
+    ) -> Self {
+        let bounds = [0, columns as i32 - 1, 0, rows as i32 - 1];
+        match orientation {
+            HexOrientation::Flat => Self::from_shape(shapes::flat_rectangle(bounds), f),
+            HexOrientation::Pointy => Self::from_shape(shapes::pointy_rectangle(bounds), f),
+        }
+    }
+
+    // Les formes de hexx sont convexes : chaque ligne est un intervalle de q
+    fn from_shape(shape: impl Iterator<Item = Hex>, mut f: impl FnMut(HexCoord) -> T) -> Self {
+        let mut coords: Vec<HexCoord> = shape.map(HexCoord::from_hex).collect();
+        coords.sort_unstable_by_key(|coord| (coord.r, coord.q));
+        Self::from_sorted(coords.into_iter().map(|coord| (coord, f(coord))))
+            .expect("les formes hexx forment une grille dense")
+    }
+
+    /// Construit la grille à partir de cellules quelconques. Renvoie `None`
+    /// si une ligne présente un trou (la forme n'est alors pas dense).
+    pub fn from_entries(entries: impl IntoIterator<Item = (HexCoord, T)>) -> Option<Self> {
+        let mut entries: Vec<(HexCoord, T)> = entries.into_iter().collect();
+        entries.sort_by_key(|(coord, _)| (coord.r, coord.q));
+        Self::from_sorted(entries)
+    }
+
+    // Cellules triées par (r, q). Les lignes absentes restent vides.
+    fn from_sorted(entries: impl IntoIterator<Item = (HexCoord, T)>) -> Option<Self> {
+        let mut entries = entries.into_iter().peekable();
+        let mut map = Self::default();
+        let Some((first, _)) = entries.peek() else {
+            return Some(map);
+        };
+        map.r_min = first.r;
+
+        for (coord, value) in entries {
+            let row_index = (coord.r - map.r_min) as usize;
+            // Lignes vides intermédiaires
+            while map.rows.len() < row_index {
+                map.rows.push(Row {
+                    q_min: 0,
+                    len: 0,
+                    start: map.values.len(),
+                });
+            }
+
+            match map.rows.get_mut(row_index) {
+                Some(row) => {
+                    if coord.q != row.q_min + row.len as i32 {
+                        return None;
+                    }
+                    row.len += 1;
+                }
+                None => map.rows.push(Row {
+                    q_min: coord.q,
+                    len: 1,
+                    start: map.values.len(),
+                }),
+            }
+
+            map.coords.push(coord);
+            map.values.push(value);
+        }
+
+        Some(map)
+    }
+
+    /// Indice dense d'une coordonnée
+    #[inline]
+    pub fn index_of(&self, coord: &HexCoord) -> Option<usize> {
+        let row = self.rows.get(usize::try_from(coord.r - self.r_min).ok()?)?;
+        let offset = u32::try_from(coord.q - row.q_min).ok()?;
+        (offset < row.len).then_some(row.start + offset as usize)
+    }
+
+    #[inline]
+    pub fn coord_at(&self, index: usize) -> Option<HexCoord> {
+        self.coords.get(index).copied()
+    }
+
+    #[inline]
+    pub fn contains(&self, coord: &HexCoord) -> bool {
+        self.index_of(coord).is_some()
+    }
+
+    #[inline]
+    pub fn get(&self, coord: &HexCoord) -> Option<&T> {
+        self.index_of(coord).map(|index| &self.values[index])
+    }
+
+    #[inline]
+    pub fn get_mut(&mut self, coord: &HexCoord) -> Option<&mut T> {
+        self.index_of(coord).map(|index| &mut self.values[index])
+    }
+
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    pub fn coords(&self) -> &[HexCoord] {
+        &self.coords
+    }
+
+    /// Valeurs dans l'ordre des indices denses
+    pub fn as_slice(&self) -> &[T] {
+        &self.values
+    }
+
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        &mut self.values
+    }
+
+    pub fn values(&self) -> impl Iterator<Item = &T> {
+        self.values.iter()
+    }
+
+    pub fn values_mut(&mut self) -> impl Iterator<Item = &mut T> {
+        self.values.iter_mut()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (HexCoord, &T)> {
+        self.coords.iter().copied().zip(self.values.iter())
+    }
+
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = (HexCoord, &mut T)> {
+        self.coords.iter().copied().zip(self.values.iter_mut())
+    }
+
+    /// Voisins présents dans la grille
+    pub fn neighbors(&self, coord: &HexCoord) -> impl Iterator<Item = (HexCoord, &T)> {
+        coord
+            .neighbors()
+            .into_iter()
+            .filter_map(|neighbor| self.get(&neighbor).map(|value| (neighbor, value)))
+    }
+
+    /// Indices denses des voisins présents (parcours de graphe sans hachage)
+    pub fn neighbor_indices(&self, index: usize) -> impl Iterator<Item = usize> + '_ {
+        self.coords[index]
+            .neighbors()
+            .into_iter()
+            .filter_map(|neighbor| self.index_of(&neighbor))
+    }
+
+    /// Nouvelle grille de même forme
+    pub fn map<U>(&self, mut f: impl FnMut(HexCoord, &T) -> U) -> HexMap<U> {
+        HexMap {
+            r_min: self.r_min,
+            rows: self.rows.clone(),
+            coords: self.coords.clone(),
+            values: self.iter().map(|(coord, value)| f(coord, value)).collect(),
+        }
+    }
+}
+
+impl<T: Send + Sync> HexMap<T> {
+    pub fn par_iter(&self) -> impl IndexedParallelIterator<Item = (HexCoord, &T)> {
+        self.coords.par_iter().copied().zip(self.values.par_iter())
+    }
+
+    pub fn par_iter_mut(&mut self) -> impl IndexedParallelIterator<Item = (HexCoord, &mut T)> {
+        self.coords.par_iter().copied().zip(self.values.par_iter_mut())
+    }
+
+    /// Nouvelle grille de même forme, calculée en parallèle
+    pub fn par_map<U: Send>(&self, f: impl Fn(HexCoord, &T) -> U + Sync) -> HexMap<U> {
+        HexMap {
+            r_min: self.r_min,
+            rows: self.rows.clone(),
+            coords: self.coords.clone(),
+            values: self.par_iter().map(|(coord, value)| f(coord, value)).collect(),
+        }
+    }
+}
+
+impl<T> Index<HexCoord> for HexMap<T> {
+    type Output = T;
+
+    fn index(&self, coord: HexCoord) -> &T {
+        self.get(&coord)
+            .unwrap_or_else(|| panic!("{:?} hors de la HexMap", coord))
+    }
+}
+
+impl<T> IndexMut<HexCoord> for HexMap<T> {
+    fn index_mut(&mut self, coord: HexCoord) -> &mut T {
+        self.get_mut(&coord)
+            .unwrap_or_else(|| panic!("{:?} hors de la HexMap", coord))
+    }
+}
+
+// Même interface que `HexagonalMap` et `RombusMap`
+impl<T> HexStore<T> for HexMap<T> {
+    #[inline]
+    fn get(&self, hex: Hex) -> Option<&T> {
+        HexMap::get(self, &HexCoord::from_hex(hex))
+    }
+
+    #[inline]
+    fn get_mut(&mut self, hex: Hex) -> Option<&mut T> {
+        HexMap::get_mut(self, &HexCoord::from_hex(hex))
+    }
+
+    fn values<'s>(&'s self) -> impl ExactSizeIterator<Item = &'s T>
+    where
+        T: 's,
+    {
+        self.values.iter()
+    }
+
+    fn values_mut<'s>(&'s mut self) -> impl ExactSizeIterator<Item = &'s mut T>
+    where
+        T: 's,
+    {
+        self.values.iter_mut()
+    }
+
+    fn iter<'s>(&'s self) -> impl ExactSizeIterator<Item = (Hex, &'s T)>
+    where
+        T: 's,
+    {
+        self.coords.iter().map(HexCoord::to_hex).zip(self.values.iter())
+    }
+
+    fn iter_mut<'s>(&'s mut self) -> impl ExactSizeIterator<Item = (Hex, &'s mut T)>
+    where
+        T: 's,
+    {
+        self.coords.iter().map(HexCoord::to_hex).zip(self.values.iter_mut())
+    }
+}
+
+// Sérialisée comme la liste de ses cellules : une grille relue depuis le
+// disque est reconstruite par `from_entries`, et rejetée si elle a des trous
+impl<T: Serialize> Serialize for HexMap<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(self.iter())
+    }
+}
+
+impl<'de, T: Deserialize<'de>> Deserialize<'de> for HexMap<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let entries = Vec::<(HexCoord, T)>::deserialize(deserializer)?;
+        Self::from_entries(entries)
+            .ok_or_else(|| D::Error::custom("les cellules ne forment pas une grille dense"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+
+    // Chaque coordonnée retrouve son indice, et les indices sont contigus
+    fn assert_consistent<T>(map: &HexMap<T>) {
+        for (index, coord) in map.coords().iter().enumerate() {
+            assert_eq!(map.index_of(coord), Some(index), "{:?}", coord);
+            assert_eq!(map.coord_at(index), Some(*coord));
+        }
+        assert_eq!(map.coords().len(), map.len());
+    }
+
+    fn coord_set<T>(map: &HexMap<T>) -> HashSet<HexCoord> {
+        map.coords().iter().copied().collect()
+    }
+
+    #[test]
+    fn hexagon_matches_hexx_shape() {
+        let center = HexCoord::new(3, -2);
+        let map = HexMap::hexagon(center, 5, |coord| coord.distance(&center));
+
+        assert_eq!(map.len(), 3 * 5 * 6 + 1);
+        assert_consistent(&map);
+        for (coord, distance) in map.iter() {
+            assert!(*distance <= 5);
+            assert_eq!(*distance, coord.distance(&center));
+        }
+        assert!(!map.contains(&HexCoord::new(3 + 6, -2)));
+    }
+
+    #[test]
+    fn rectangle_matches_hexx_shapes() {
+        let to_set = |shape: &mut dyn Iterator<Item = Hex>| -> HashSet<HexCoord> {
+            shape.map(HexCoord::from_hex).collect()
+        };
+        for (orientation, expected) in [
+            (HexOrientation::Flat, to_set(&mut shapes::flat_rectangle([0, 6, 0, 4]))),
+            (HexOrientation::Pointy, to_set(&mut shapes::pointy_rectangle([0, 6, 0, 4]))),
+        ] {
+            let map = HexMap::rectangle(orientation, 7, 5, |_| ());
+
+            assert_eq!(map.len(), 7 * 5);
+            assert_eq!(coord_set(&map), expected);
+            assert_consistent(&map);
+        }
+    }
+
+    #[test]
+    fn empty_rectangle() {
+        let map = HexMap::rectangle(HexOrientation::Flat, 0, 4, |_| ());
+        assert!(map.is_empty());
+        assert!(!map.contains(&HexCoord::new(0, 0)));
+    }
+
+    #[test]
+    fn from_entries_accepts_unordered_cells_and_empty_rows() {
+        let coords = [
+            HexCoord::new(2, 3),
+            HexCoord::new(-1, 0),
+            HexCoord::new(0, 0),
+            HexCoord::new(1, 3),
+        ];
+        let map = HexMap::from_entries(coords.map(|coord| (coord, coord.q * 10 + coord.r)))
+            .expect("lignes contiguës");
+
+        assert_eq!(map.len(), coords.len());
+        assert_consistent(&map);
+        for coord in coords {
+            assert_eq!(map[coord], coord.q * 10 + coord.r);
+        }
+        // Ligne r = 1 vide
+        assert!(!map.contains(&HexCoord::new(0, 1)));
+    }
+
+    #[test]
+    fn from_entries_rejects_holes_and_duplicates() {
+        let hole = [HexCoord::new(0, 0), HexCoord::new(2, 0)];
+        assert!(HexMap::from_entries(hole.map(|coord| (coord, ()))).is_none());
+
+        let duplicate = [HexCoord::new(0, 0), HexCoord::new(0, 0)];
+        assert!(HexMap::from_entries(duplicate.map(|coord| (coord, ()))).is_none());
+    }
+
+    #[test]
+    fn neighbor_indices_stay_in_grid() {
+        let map = HexMap::hexagon(HexCoord::new(0, 0), 2, |_| ());
+        let center = map.index_of(&HexCoord::new(0, 0)).unwrap();
+        let corner = map.index_of(&HexCoord::new(2, 0)).unwrap();
+
+        assert_eq!(map.neighbor_indices(center).count(), 6);
+        assert_eq!(map.neighbor_indices(corner).count(), 3);
+    }
+
+    #[test]
+    fn serde_round_trip_checks_density() {
+        let map = HexMap::hexagon(HexCoord::new(0, 0), 3, |coord| coord.q - coord.r);
+        let bytes = bincode::serialize(&map).unwrap();
+        let restored: HexMap<i32> = bincode::deserialize(&bytes).unwrap();
+        assert_eq!(restored.as_slice(), map.as_slice());
+        assert_eq!(restored.coords(), map.coords());
+
+        let holes = vec![(HexCoord::new(0, 0), 1), (HexCoord::new(5, 0), 2)];
+        let bytes = bincode::serialize(&holes).unwrap();
+        assert!(bincode::deserialize::<HexMap<i32>>(&bytes).is_err());
+    }
+}
@@ -1,2 +1,8 @@
+mod field_of_view;
+pub use field_of_view::*;
 mod hex_coord;
 pub use hex_coord::*;
+mod hex_map;
+pub use hex_map::*;
+mod regions;
+pub use regions::*;
@@ -0,0 +1,190 @@
+use bevy::prelude::*;
+use std::collections::VecDeque;
+
+use super::hex_coord::HexCoord;
+use super::hex_map::HexMap;
+
+pub type RegionId = u32;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum RegionKind {
+    Island,
+    // Eau entièrement entourée de terre
+    Lake,
+    // Eau reliée au bord de la grille
+    Ocean,
+}
+
+/// Composante connexe de cellules de terre ou d'eau
+#[derive(Clone, Debug)]
+pub struct Region {
+    pub id: RegionId,
+    pub kind: RegionKind,
+    // Surface en hexagones
+    pub area: usize,
+}
+
+impl Region {
+    pub fn is_water(&self) -> bool {
+        self.kind != RegionKind::Island
+    }
+}
+
+/// Îles, lacs et océans de la carte, étiquetés par composantes connexes
+#[derive(Resource, Clone, Debug, Default)]
+pub struct HexRegions {
+    labels: HexMap<RegionId>,
+    regions: Vec<Region>,
+}
+
+impl HexRegions {
+    /// Étiquette les cellules (est-ce de la terre). Une étendue d'eau qui
+    /// touche le bord de la grille est un océan, sinon un lac.
+    pub fn compute(is_land: &HexMap<bool>) -> Self {
+        let mut labels = is_land.map(|_, _| RegionId::MAX);
+        let mut regions = Vec::new();
+
+        // Parcours dans l'ordre de la grille : les identifiants sont stables
+        // d'une génération à l'autre
+        for start in 0..is_land.len() {
+            if labels.as_slice()[start] != RegionId::MAX {
+                continue;
+            }
+            let land = is_land.as_slice()[start];
+
+            let id = regions.len() as RegionId;
+            let mut area = 0;
+            let mut touches_border = false;
+            let mut queue = VecDeque::from([start]);
+            labels.as_mut_slice()[start] = id;
+
+            while let Some(index) = queue.pop_front() {
+                area += 1;
+                let mut neighbor_count = 0;
+                for neighbor in is_land.neighbor_indices(index) {
+                    neighbor_count += 1;
+                    let label = &mut labels.as_mut_slice()[neighbor];
+                    if is_land.as_slice()[neighbor] == land && *label == RegionId::MAX {
+                        *label = id;
+                        queue.push_back(neighbor);
+                    }
+                }
+                touches_border |= neighbor_count < 6;
+            }
+
+            let kind = match (land, touches_border) {
+                (true, _) => RegionKind::Island,
+                (false, true) => RegionKind::Ocean,
+                (false, false) => RegionKind::Lake,
+            };
+            regions.push(Region { id, kind, area });
+        }
+
+        Self { labels, regions }
+    }
+
+    /// Région contenant la cellule
+    pub fn region_of(&self, coord: &HexCoord) -> Option<&Region> {
+        let id = self.labels.get(coord)?;
+        self.regions.get(*id as usize)
+    }
+
+    pub fn get(&self, id: RegionId) -> Option<&Region> {
+        self.regions.get(id as usize)
+    }
+
+    pub fn regions(&self) -> &[Region] {
+        &self.regions
+    }
+
+    pub fn islands(&self) -> impl Iterator<Item = &Region> {
+        self.of_kind(RegionKind::Island)
+    }
+
+    pub fn lakes(&self) -> impl Iterator<Item = &Region> {
+        self.of_kind(RegionKind::Lake)
+    }
+
+    fn of_kind(&self, kind: RegionKind) -> impl Iterator<Item = &Region> {
+        self.regions.iter().filter(move |region| region.kind == kind)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Île de rayon 3 percée d'un lac de deux cellules, dans un océan de rayon 6
+    fn island_with_lake() -> HexMap<bool> {
+        let center = HexCoord::new(0, 0);
+        let lake = [center, HexCoord::new(1, 0)];
+        HexMap::hexagon(center, 6, |coord| {
+            coord.distance(&center) <= 3 && !lake.contains(&coord)
+        })
+    }
+
+    #[test]
+    fn island_lake_and_ocean() {
+        let regions = HexRegions::compute(&island_with_lake());
+        assert_eq!(regions.regions().len(), 3);
+
+        let island = regions.region_of(&HexCoord::new(0, 2)).unwrap();
+        assert_eq!(island.kind, RegionKind::Island);
+        assert_eq!(island.area, 37 - 2);
+        assert!(!island.is_water());
+
+        let lake = regions.region_of(&HexCoord::new(0, 0)).unwrap();
+        assert_eq!(lake.kind, RegionKind::Lake);
+        assert_eq!(lake.area, 2);
+        assert!(lake.is_water());
+        assert_eq!(regions.region_of(&HexCoord::new(1, 0)).unwrap().id, lake.id);
+
+        let ocean = regions.region_of(&HexCoord::new(-5, 0)).unwrap();
+        assert_eq!(ocean.kind, RegionKind::Ocean);
+        assert_eq!(ocean.area, 127 - 37);
+        let far_ocean = regions.region_of(&HexCoord::new(4, -4)).unwrap();
+        assert_eq!(far_ocean.id, ocean.id);
+
+        assert_eq!(regions.islands().count(), 1);
+        let lakes: Vec<_> = regions.lakes().map(|lake| lake.id).collect();
+        assert_eq!(lakes, [lake.id]);
+        for region in regions.regions() {
+            assert_eq!(regions.get(region.id).unwrap().kind, region.kind);
+        }
+    }
+
+    #[test]
+    fn region_of_outside_grid_is_none() {
+        let regions = HexRegions::compute(&island_with_lake());
+        assert!(regions.region_of(&HexCoord::new(7, 0)).is_none());
+    }
+
+    #[test]
+    fn water_touching_border_is_ocean() {
+        // Sans anneau d'océan, l'eau du centre touche le bord d'une grille de rayon 1
+        let center = HexCoord::new(0, 0);
+        let is_land = HexMap::hexagon(center, 1, |coord| coord != center && coord.q != 1);
+        let regions = HexRegions::compute(&is_land);
+
+        assert_eq!(regions.region_of(&center).unwrap().kind, RegionKind::Ocean);
+        assert_eq!(regions.lakes().count(), 0);
+    }
+
+    #[test]
+    fn ids_follow_grid_order() {
+        let is_land = island_with_lake();
+        let first = HexRegions::compute(&is_land);
+        let second = HexRegions::compute(&is_land);
+
+        for (index, coord) in is_land.coords().iter().enumerate() {
+            let id = first.region_of(coord).unwrap().id;
+            assert_eq!(second.region_of(coord).unwrap().id, id);
+            // Une nouvelle région reçoit le plus petit identifiant libre
+            let seen = is_land.coords()[..index]
+                .iter()
+                .map(|coord| first.region_of(coord).unwrap().id)
+                .max();
+            assert!(id <= seen.map_or(0, |seen| seen + 1));
+        }
+    }
+}
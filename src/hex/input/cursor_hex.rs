@@ -0,0 +1,29 @@
+use bevy::ecs::system::SystemParam;
+use bevy::prelude::*;
+use bevy::window::PrimaryWindow;
+
+use crate::camera::MainCamera;
+use crate::hex::HexCoord;
+use crate::hex::rendering::config::HexConfig;
+
+/// Hexagone sous le curseur de la fenêtre principale, vu par la caméra principale
+#[derive(SystemParam)]
+pub struct CursorHex<'w, 's> {
+    windows: Query<'w, 's, &'static Window, With<PrimaryWindow>>,
+    cameras: Query<'w, 's, (&'static Camera, &'static GlobalTransform), With<MainCamera>>,
+    hex_config: Res<'w, HexConfig>,
+}
+
+impl CursorHex<'_, '_> {
+    /// `None` quand le curseur est hors de la fenêtre. Échoue sans fenêtre
+    /// principale ou caméra principale unique.
+    pub fn hex(&self) -> Result<Option<HexCoord>> {
+        let window = self.windows.single()?;
+        let (camera, camera_transform) = self.cameras.single()?;
+        let hex = window
+            .cursor_position()
+            .and_then(|p| camera.viewport_to_world_2d(camera_transform, p).ok())
+            .map(|position| HexCoord::from_hex(self.hex_config.layout.world_pos_to_hex(position)));
+        Ok(hex)
+    }
+}
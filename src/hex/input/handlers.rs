@@ -2,22 +2,22 @@ use bevy::prelude::*;
 use bevy::window::PrimaryWindow;
 
 use crate::camera::MainCamera;
-use crate::hex;
+use crate::editor::{EditCommand, EditRequest};
 use crate::hex::HexCoord;
-use crate::hex::rendering::atlas::ColorTintMaterials;
-use crate::hex::rendering::components::{HexTile, HexVisuals};
+use crate::hex::rendering::components::HexTile;
 use crate::hex::rendering::config::HexConfig;
+use crate::input::{ActionInput, InputAction};
+use crate::shared::types::ChunkId;
 
 pub fn spawn_on_click(
-    mut commands: Commands,
-    mouse_input: Res<ButtonInput<MouseButton>>,
+    input: ActionInput,
     windows: Query<&Window, With<PrimaryWindow>>,
     cameras: Query<(&Camera, &GlobalTransform), With<MainCamera>>,
     hex_config: Res<HexConfig>,
-    color_tint_materials: Res<ColorTintMaterials>,
     existing: Query<&HexTile>,
+    mut edits: MessageWriter<EditRequest>,
 ) -> Result {
-    if !mouse_input.just_pressed(MouseButton::Left) {
+    if !input.just_pressed(InputAction::Select) {
         return Ok(());
     }
 
@@ -32,32 +32,26 @@ pub fn spawn_on_click(
         // info!("Position: {}", position);
         let hex_position = hex_config.layout.world_pos_to_hex(position);
         let hex_coord = HexCoord::from_hex(hex_position);
-        let world_pos = hex_config.layout.hex_to_world_pos(hex_position);
 
         if existing_coords.contains(&hex_coord) {
             return Ok(());
         }
-        hex::rendering::spawn_hex_sprite(
-            &mut commands,
-            hex_coord,
-            world_pos,
-            color_tint_materials.clone(),
-            existing_coords.clone(),
-        );
+        edits.write(EditRequest::new(EditCommand::SpawnTiles {
+            coords: vec![hex_coord],
+        }));
     }
     Ok(())
 }
 
 pub fn spawn_chunk_on_click(
-    mut commands: Commands,
-    mouse_input: Res<ButtonInput<MouseButton>>,
+    input: ActionInput,
     windows: Query<&Window, With<PrimaryWindow>>,
     cameras: Query<(&Camera, &GlobalTransform), With<MainCamera>>,
     hex_config: Res<HexConfig>,
-    color_tint_materials: Res<ColorTintMaterials>,
     existing: Query<&HexTile>,
+    mut edits: MessageWriter<EditRequest>,
 ) -> Result {
-    if !mouse_input.just_pressed(MouseButton::Left) {
+    if !input.just_pressed(InputAction::Select) {
         return Ok(());
     }
 
@@ -74,20 +68,22 @@ pub fn spawn_chunk_on_click(
         let hex_coord = HexCoord::from_hex(clicked_hex);
 
         info!("Clicked on: {:?}", hex_coord);
-        // let world_pos = hex_config.chunk_layout.hex_to_world_pos(chunk_hex_position);
 
         if existing_coords.contains(&hex_coord) {
             warn!("Coord {:?} already exists. Abort chunk spawn.", hex_coord);
             return Ok(());
         }
-        hex::rendering::spawn_hex_chunk(
-            &mut commands,
-            hex_config.clone(),
-            hex_coord,
-            color_tint_materials.clone(),
-            existing_coords.clone(),
-            hex_config.chunk_size
-        );
+
+        let chunk_id = ChunkId::from_hex_coord(hex_coord, hex_config.chunk_size);
+        info!("Spawning chunk {:?}", chunk_id.coord);
+
+        // Seules les tuiles absentes sont spawnées (et donc annulables)
+        let coords: Vec<HexCoord> = chunk_id
+            .hex_coords()
+            .into_iter()
+            .filter(|coord| !existing_coords.contains(coord))
+            .collect();
+        edits.write(EditRequest::new(EditCommand::SpawnTiles { coords }));
     }
     Ok(())
 }
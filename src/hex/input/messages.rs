@@ -0,0 +1,36 @@
+use bevy::prelude::*;
+
+use crate::hex::HexCoord;
+use crate::shared::types::ChunkId;
+
+/// Clic sur un hexagone (tuile ou mesh de chunk)
+#[derive(Message, Clone, Copy, Debug)]
+pub struct HexClicked {
+    pub coord: HexCoord,
+    pub chunk: ChunkId,
+    pub button: PointerButton,
+}
+
+/// Le pointeur entre sur un hexagone
+#[derive(Message, Clone, Copy, Debug)]
+pub struct HexHovered {
+    pub coord: HexCoord,
+    pub chunk: ChunkId,
+}
+
+/// Le pointeur quitte un hexagone
+#[derive(Message, Clone, Copy, Debug)]
+pub struct HexHoverEnded {
+    pub coord: HexCoord,
+    pub chunk: ChunkId,
+}
+
+/// Glisser depuis un hexagone, `coord` étant l'hexagone sous le pointeur
+#[derive(Message, Clone, Copy, Debug)]
+pub struct HexDragged {
+    pub coord: HexCoord,
+    pub chunk: ChunkId,
+    pub button: PointerButton,
+    // Déplacement écran depuis le dernier événement
+    pub delta: Vec2,
+}
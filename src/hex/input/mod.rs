@@ -1,4 +1,12 @@
+pub mod cursor_hex;
 pub mod handlers;
+pub mod messages;
+pub mod picking_backend;
 pub mod plugin;
+pub mod pointer;
 
-pub use plugin::HexInputPlugin;
\ No newline at end of file
+pub use cursor_hex::CursorHex;
+pub use messages::{HexClicked, HexDragged, HexHoverEnded, HexHovered};
+pub use picking_backend::{HexEntityIndex, HexPickingPlugin, HexPickingSurface};
+pub use plugin::{HexInputPlugin, HexPointerPlugin};
+pub use pointer::HoveredHex;
\ No newline at end of file
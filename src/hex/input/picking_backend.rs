@@ -0,0 +1,136 @@
+use bevy::ecs::system::SystemParam;
+use bevy::picking::PickingSystems;
+use bevy::picking::backend::{HitData, PointerHits};
+use bevy::picking::pointer::{PointerId, PointerLocation};
+use bevy::prelude::*;
+use bevy::window::PrimaryWindow;
+use bevy_inspector_egui::bevy_egui::input::EguiWantsInput;
+use std::collections::HashMap;
+
+use crate::camera::MainCamera;
+use crate::hex::HexCoord;
+use crate::hex::rendering::components::HexTile;
+use crate::hex::rendering::config::HexConfig;
+
+/// Entités de tuile indexées par coordonnée
+#[derive(Resource, Default, Debug)]
+pub struct HexEntityIndex {
+    pub tiles: HashMap<HexCoord, Entity>,
+}
+
+/// Entité touchée quand aucune tuile ne couvre l'hexagone : le survol et
+/// les clics restent signalés sur toute la grille
+#[derive(Component)]
+pub struct HexPickingSurface;
+
+// Profondeurs relatives : la tuile masque la surface
+const TILE_DEPTH: f32 = 0.0;
+const SURFACE_DEPTH: f32 = 1.0;
+
+/// Backend de picking analytique : la position du pointeur est convertie en
+/// hexagone avec le layout, puis en entités via l'index. Coût constant,
+/// indépendant du nombre de tuiles. Un chunk n'a pas d'entité propre : ses
+/// tuiles sont touchées, et `ChunkId::from_hex_coord` donne le chunk visé.
+pub struct HexPickingPlugin;
+
+impl Plugin for HexPickingPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<HexEntityIndex>()
+            .add_observer(index_tile)
+            .add_observer(unindex_tile)
+            .add_systems(Startup, spawn_picking_surface)
+            .add_systems(PreUpdate, hex_picking.in_set(PickingSystems::Backend));
+    }
+}
+
+fn spawn_picking_surface(mut commands: Commands) {
+    commands.spawn((Name::new("Surface hexagonale"), HexPickingSurface));
+}
+
+pub fn hex_picking(
+    pointers: Query<(&PointerId, &PointerLocation)>,
+    primary_window: Query<Entity, With<PrimaryWindow>>,
+    cameras: Query<(Entity, &Camera, &GlobalTransform), With<MainCamera>>,
+    hex_config: Option<Res<HexConfig>>,
+    targets: HexHitTargets,
+    egui: Option<Res<EguiWantsInput>>,
+    mut output: MessageWriter<PointerHits>,
+) {
+    let Some(hex_config) = hex_config else {
+        return;
+    };
+    // Sous un panneau egui, la grille n'est ni survolée ni cliquée
+    if egui.is_some_and(|egui| egui.wants_pointer_input()) {
+        return;
+    }
+    let primary_window = primary_window.single().ok();
+
+    for (pointer_id, pointer_location) in &pointers {
+        let Some(location) = pointer_location.location() else {
+            continue;
+        };
+
+        let Some((camera_entity, camera, camera_transform)) = cameras
+            .iter()
+            .filter(|(_, camera, _)| camera.is_active)
+            .find(|(_, camera, _)| {
+                camera
+                    .target
+                    .normalize(primary_window)
+                    .is_some_and(|target| target == location.target)
+            })
+        else {
+            continue;
+        };
+
+        let Ok(world_pos) = camera.viewport_to_world_2d(camera_transform, location.position) else {
+            continue;
+        };
+
+        let coord = HexCoord::from_hex(hex_config.layout.world_pos_to_hex(world_pos));
+        let hits = targets.hits(coord, camera_entity, world_pos);
+
+        output.write(PointerHits::new(*pointer_id, hits, camera.order as f32));
+    }
+}
+
+/// Entités touchées sur un hexagone : sa tuile, puis la surface de picking
+#[derive(SystemParam)]
+pub struct HexHitTargets<'w, 's> {
+    index: Res<'w, HexEntityIndex>,
+    surface: Query<'w, 's, Entity, With<HexPickingSurface>>,
+}
+
+impl HexHitTargets<'_, '_> {
+    fn hits(&self, coord: HexCoord, camera: Entity, world_pos: Vec2) -> Vec<(Entity, HitData)> {
+        let position = Some(world_pos.extend(0.0));
+        [
+            (self.index.tiles.get(&coord).copied(), TILE_DEPTH),
+            (self.surface.iter().next(), SURFACE_DEPTH),
+        ]
+        .into_iter()
+        .filter_map(|(entity, depth)| {
+            let hit = HitData::new(camera, depth, position, Some(Vec3::Z));
+            entity.map(|entity| (entity, hit))
+        })
+        .collect()
+    }
+}
+
+fn index_tile(event: On<Add, HexTile>, tiles: Query<&HexTile>, mut index: ResMut<HexEntityIndex>) {
+    if let Ok(tile) = tiles.get(event.entity) {
+        index.tiles.insert(tile.coord, event.entity);
+    }
+}
+
+fn unindex_tile(
+    event: On<Remove, HexTile>,
+    tiles: Query<&HexTile>,
+    mut index: ResMut<HexEntityIndex>,
+) {
+    if let Ok(tile) = tiles.get(event.entity)
+        && index.tiles.get(&tile.coord) == Some(&event.entity)
+    {
+        index.tiles.remove(&tile.coord);
+    }
+}
@@ -1,7 +1,10 @@
 use bevy::prelude::*;
 
 use super::handlers;
+use super::messages::{HexClicked, HexDragged, HexHoverEnded, HexHovered};
+use super::pointer;
 
+/// Les messages `EditRequest` sont enregistrés par EditorPlugin
 pub struct HexInputPlugin;
 
 impl Plugin for HexInputPlugin {
@@ -9,3 +12,22 @@ impl Plugin for HexInputPlugin {
         app.add_systems(Update, handlers::spawn_chunk_on_click);
     }
 }
+
+
+/// Traduit les événements de pointeur en messages `HexClicked`, `HexHovered`,
+/// `HexHoverEnded` et `HexDragged`, pour les tuiles comme pour la surface de picking
+pub struct HexPointerPlugin;
+
+impl Plugin for HexPointerPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<pointer::HoveredHex>()
+            .add_message::<HexClicked>()
+            .add_message::<HexHovered>()
+            .add_message::<HexHoverEnded>()
+            .add_message::<HexDragged>()
+            .add_observer(pointer::on_pointer_move)
+            .add_observer(pointer::on_pointer_out)
+            .add_observer(pointer::on_pointer_click)
+            .add_observer(pointer::on_pointer_drag);
+    }
+}
@@ -0,0 +1,173 @@
+use bevy::ecs::system::SystemParam;
+use bevy::prelude::*;
+
+use super::messages::{HexClicked, HexDragged, HexHoverEnded, HexHovered};
+use super::picking_backend::HexPickingSurface;
+use crate::camera::MainCamera;
+use crate::hex::HexCoord;
+use crate::hex::rendering::components::HexTile;
+use crate::hex::rendering::config::HexConfig;
+use crate::shared::types::ChunkId;
+
+/// Hexagone actuellement survolé, que le pointeur soit sur une tuile ou sur
+/// la surface de picking
+#[derive(Resource, Default, Debug)]
+pub struct HoveredHex(pub Option<HexCoord>);
+
+/// Hexagone visé par un événement de pointeur
+#[derive(SystemParam)]
+pub struct HexLocator<'w, 's> {
+    tiles: Query<'w, 's, &'static HexTile>,
+    cameras: Query<'w, 's, (&'static Camera, &'static GlobalTransform), With<MainCamera>>,
+    hex_config: Res<'w, HexConfig>,
+}
+
+impl HexLocator<'_, '_> {
+    // Hexagone visé : celui de la tuile si l'entité en est une, sinon position
+    // du hit (surface de picking), sinon projection du pointeur
+    fn target_hex(
+        &self,
+        entity: Option<Entity>,
+        hit_position: Option<Vec3>,
+        pointer_position: Vec2,
+    ) -> Option<HexCoord> {
+        if let Some(tile) = entity.and_then(|entity| self.tiles.get(entity).ok()) {
+            return Some(tile.coord);
+        }
+
+        let world_pos = match hit_position {
+            Some(position) => position.truncate(),
+            None => {
+                let (camera, camera_transform) = self.cameras.single().ok()?;
+                camera
+                    .viewport_to_world_2d(camera_transform, pointer_position)
+                    .ok()?
+            }
+        };
+        Some(HexCoord::from_hex(
+            self.hex_config.layout.world_pos_to_hex(world_pos),
+        ))
+    }
+
+    fn chunk_of(&self, coord: HexCoord) -> ChunkId {
+        chunk_of(coord, &self.hex_config)
+    }
+}
+
+fn chunk_of(coord: HexCoord, hex_config: &HexConfig) -> ChunkId {
+    ChunkId::from_hex_coord(coord, hex_config.chunk_size)
+}
+
+// Entités touchées par HexPickingPlugin : tuiles et surface de picking (les
+// meshes du terrain ne sont pas pickables sans MeshPickingPlugin).
+// Les nœuds d'UI (HUD, ...) ne produisent pas de messages hexagonaux.
+type HexTarget = Or<(With<HexTile>, With<HexPickingSurface>)>;
+
+// Les événements de pointeur remontent vers les parents : seul l'événement
+// d'origine est traduit, et seulement s'il vise la grille
+fn is_hex_event<T>(event: &On<Pointer<T>>, targets: &Query<(), HexTarget>) -> bool
+where
+    T: Clone + std::fmt::Debug + Reflect + Send + Sync + 'static,
+{
+    event.event_target() == event.original_event_target() && targets.contains(event.event_target())
+}
+
+pub fn on_pointer_move(
+    event: On<Pointer<Move>>,
+    targets: Query<(), HexTarget>,
+    mut hovered: ResMut<HoveredHex>,
+    mut hover_started: MessageWriter<HexHovered>,
+    mut hover_ended: MessageWriter<HexHoverEnded>,
+    locator: HexLocator,
+) {
+    if !is_hex_event(&event, &targets) {
+        return;
+    }
+    let Some(coord) = locator.target_hex(
+        Some(event.event_target()),
+        event.hit.position,
+        event.pointer_location.position,
+    ) else {
+        return;
+    };
+
+    // La surface de picking couvre plusieurs hexagones : le survol change sans Over/Out
+    if hovered.0 == Some(coord) {
+        return;
+    }
+    if let Some(previous) = hovered.0.replace(coord) {
+        hover_ended.write(HexHoverEnded {
+            coord: previous,
+            chunk: locator.chunk_of(previous),
+        });
+    }
+    hover_started.write(HexHovered {
+        coord,
+        chunk: locator.chunk_of(coord),
+    });
+}
+
+pub fn on_pointer_out(
+    event: On<Pointer<Out>>,
+    targets: Query<(), HexTarget>,
+    mut hovered: ResMut<HoveredHex>,
+    mut hover_ended: MessageWriter<HexHoverEnded>,
+    hex_config: Res<HexConfig>,
+) {
+    if !is_hex_event(&event, &targets) {
+        return;
+    }
+    if let Some(previous) = hovered.0.take() {
+        hover_ended.write(HexHoverEnded {
+            coord: previous,
+            chunk: chunk_of(previous, &hex_config),
+        });
+    }
+}
+
+pub fn on_pointer_click(
+    event: On<Pointer<Click>>,
+    targets: Query<(), HexTarget>,
+    mut clicked: MessageWriter<HexClicked>,
+    locator: HexLocator,
+) {
+    if !is_hex_event(&event, &targets) {
+        return;
+    }
+    let Some(coord) = locator.target_hex(
+        Some(event.event_target()),
+        event.hit.position,
+        event.pointer_location.position,
+    ) else {
+        return;
+    };
+
+    clicked.write(HexClicked {
+        coord,
+        chunk: locator.chunk_of(coord),
+        button: event.button,
+    });
+}
+
+pub fn on_pointer_drag(
+    event: On<Pointer<Drag>>,
+    targets: Query<(), HexTarget>,
+    mut dragged: MessageWriter<HexDragged>,
+    locator: HexLocator,
+) {
+    if !is_hex_event(&event, &targets) {
+        return;
+    }
+    // Pas de hit pendant un glisser : l'hexagone est celui sous le pointeur,
+    // pas celui de l'entité où le glisser a commencé
+    let Some(coord) = locator.target_hex(None, None, event.pointer_location.position) else {
+        return;
+    };
+
+    dragged.write(HexDragged {
+        coord,
+        chunk: locator.chunk_of(coord),
+        button: event.button,
+        delta: event.delta,
+    });
+}
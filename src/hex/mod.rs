@@ -1,5 +1,6 @@
 pub mod components;
 pub mod input;
+pub mod pathfinding;
 pub mod rendering;
 
 pub use components::*;
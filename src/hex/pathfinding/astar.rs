@@ -0,0 +1,189 @@
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, HashMap};
+
+use crate::hex::HexCoord;
+
+/// Chemin trouvé, départ et arrivée compris
+#[derive(Clone, Debug, PartialEq)]
+pub struct HexPath {
+    pub coords: Vec<HexCoord>,
+    pub cost: f32,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PathError {
+    // Départ ou arrivée infranchissable
+    Impassable,
+    // Toutes les cellules atteignables ont été explorées
+    Unreachable,
+    // Budget de cellules explorées épuisé avant d'atteindre l'arrivée
+    BudgetExceeded,
+}
+
+/// A* sur la grille hexagonale. `cost` donne le coût pour entrer dans une
+/// cellule (`None` = infranchissable) et `min_cost` le plus petit coût
+/// possible, qui garde l'heuristique admissible. `max_expansions` borne le
+/// nombre de cellules explorées.
+pub fn find_path(
+    start: HexCoord,
+    goal: HexCoord,
+    max_expansions: usize,
+    min_cost: f32,
+    cost: impl Fn(&HexCoord) -> Option<f32>,
+) -> Result<HexPath, PathError> {
+    if cost(&start).is_none() || cost(&goal).is_none() {
+        return Err(PathError::Impassable);
+    }
+
+    let heuristic = |coord: &HexCoord| coord.distance(&goal) as f32 * min_cost.max(0.0);
+
+    let mut open = BinaryHeap::from([OpenNode {
+        coord: start,
+        estimate: heuristic(&start),
+    }]);
+    let mut best_cost = HashMap::from([(start, 0.0f32)]);
+    let mut came_from: HashMap<HexCoord, HexCoord> = HashMap::new();
+    let mut expansions = 0;
+
+    while let Some(OpenNode { coord, estimate }) = open.pop() {
+        let cost_so_far = best_cost[&coord];
+        // Entrée périmée : un meilleur chemin vers cette cellule a déjà été traité
+        if estimate > cost_so_far + heuristic(&coord) {
+            continue;
+        }
+
+        if coord == goal {
+            return Ok(HexPath {
+                coords: reconstruct_path(&came_from, goal),
+                cost: cost_so_far,
+            });
+        }
+
+        expansions += 1;
+        if expansions > max_expansions {
+            return Err(PathError::BudgetExceeded);
+        }
+
+        for neighbor in coord.neighbors() {
+            let Some(step_cost) = cost(&neighbor) else {
+                continue;
+            };
+            let next_cost = cost_so_far + step_cost;
+            if best_cost
+                .get(&neighbor)
+                .is_some_and(|&known| known <= next_cost)
+            {
+                continue;
+            }
+
+            best_cost.insert(neighbor, next_cost);
+            came_from.insert(neighbor, coord);
+            open.push(OpenNode {
+                coord: neighbor,
+                estimate: next_cost + heuristic(&neighbor),
+            });
+        }
+    }
+
+    Err(PathError::Unreachable)
+}
+
+fn reconstruct_path(came_from: &HashMap<HexCoord, HexCoord>, goal: HexCoord) -> Vec<HexCoord> {
+    let mut coords = vec![goal];
+    let mut current = goal;
+    while let Some(previous) = came_from.get(&current) {
+        coords.push(*previous);
+        current = *previous;
+    }
+    coords.reverse();
+    coords
+}
+
+// Entrée de la file de priorité, ordonnée par estimation croissante
+struct OpenNode {
+    coord: HexCoord,
+    estimate: f32,
+}
+
+impl PartialEq for OpenNode {
+    fn eq(&self, other: &Self) -> bool {
+        self.estimate.total_cmp(&other.estimate) == Ordering::Equal
+    }
+}
+
+impl Eq for OpenNode {}
+
+impl PartialOrd for OpenNode {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for OpenNode {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // BinaryHeap est un tas max : ordre inversé pour sortir la plus petite estimation
+        other.estimate.total_cmp(&self.estimate)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Grille hexagonale de rayon 10 autour de l'origine, coût uniforme
+    fn bounded(coord: &HexCoord) -> Option<f32> {
+        (coord.distance(&HexCoord::new(0, 0)) <= 10).then_some(1.0)
+    }
+
+    #[test]
+    fn straight_line() {
+        let goal = HexCoord::new(5, 0);
+        let path = find_path(HexCoord::new(0, 0), goal, 1_000, 1.0, bounded).unwrap();
+
+        assert_eq!(path.coords.len(), 6);
+        assert_eq!(path.coords.first(), Some(&HexCoord::new(0, 0)));
+        assert_eq!(path.coords.last(), Some(&goal));
+        assert_eq!(path.cost, 5.0);
+        for step in path.coords.windows(2) {
+            assert_eq!(step[0].distance(&step[1]), 1);
+        }
+    }
+
+    #[test]
+    fn impassable_wall() {
+        // Colonne q = 2 infranchissable sur toute la hauteur de la grille
+        let walled = |coord: &HexCoord| if coord.q == 2 { None } else { bounded(coord) };
+
+        let blocked = find_path(HexCoord::new(0, 0), HexCoord::new(4, 0), 1_000, 1.0, walled);
+        assert_eq!(blocked, Err(PathError::Unreachable));
+
+        let on_wall = find_path(HexCoord::new(0, 0), HexCoord::new(2, 0), 1_000, 1.0, walled);
+        assert_eq!(on_wall, Err(PathError::Impassable));
+    }
+
+    #[test]
+    fn wall_with_gap_is_bypassed() {
+        let gap = HexCoord::new(2, 3);
+        let walled = |coord: &HexCoord| {
+            if coord.q == 2 && *coord != gap { None } else { bounded(coord) }
+        };
+
+        let path = find_path(HexCoord::new(0, 0), HexCoord::new(4, 0), 1_000, 1.0, walled).unwrap();
+        assert!(path.coords.contains(&gap));
+        assert!(path.cost > 4.0);
+    }
+
+    #[test]
+    fn max_expansions_runs_out() {
+        let result = find_path(HexCoord::new(0, 0), HexCoord::new(100, 0), 10, 0.0, |_| Some(1.0));
+        assert_eq!(result, Err(PathError::BudgetExceeded));
+    }
+
+    #[test]
+    fn goal_is_start() {
+        let start = HexCoord::new(3, -1);
+        let path = find_path(start, start, 0, 1.0, bounded).unwrap();
+        assert_eq!(path.coords, vec![start]);
+        assert_eq!(path.cost, 0.0);
+    }
+}
@@ -0,0 +1,118 @@
+use bevy::color::palettes::tailwind::{GREEN_400, RED_500};
+use bevy::prelude::*;
+
+use super::{HexPath, PathError, PathfindingConfig, find_path};
+use crate::editor::EditorState;
+use crate::hex::HexCoord;
+use crate::hex::input::CursorHex;
+use crate::hex::rendering::config::HexConfig;
+use crate::hex::rendering::contour::terrain::TerrainCells;
+use crate::input::{ActionInput, InputAction};
+use crate::state::components::TerrainOverrides;
+
+/// Mode debug : chemin entre deux hexagones cliqués
+#[derive(Resource, Default)]
+pub struct PathDebug {
+    pub enabled: bool,
+    pub start: Option<HexCoord>,
+    pub goal: Option<HexCoord>,
+    pub result: Option<Result<HexPath, PathError>>,
+}
+
+/// P (par défaut) active/désactive le mode debug du pathfinding
+pub fn toggle_path_debug(input: ActionInput, mut path_debug: ResMut<PathDebug>) {
+    if input.just_pressed(InputAction::TogglePathDebug) {
+        path_debug.enabled = !path_debug.enabled;
+        path_debug.start = None;
+        path_debug.goal = None;
+        path_debug.result = None;
+        info!(
+            "🧭 Debug pathfinding {}",
+            if path_debug.enabled { "activé" } else { "désactivé" }
+        );
+    }
+}
+
+/// Premier clic : départ, second clic : arrivée et calcul du chemin
+pub fn pick_path_endpoints(
+    mut debug: ResMut<PathDebug>,
+    editor: Option<Res<EditorState>>,
+    input: ActionInput,
+    cursor: CursorHex,
+    config: Res<PathfindingConfig>,
+    cells: Option<Res<TerrainCells>>,
+    overrides: Option<Res<TerrainOverrides>>,
+) -> Result {
+    // Le clic gauche appartient à l'éditeur quand il est actif
+    if !debug.enabled || editor.is_some_and(|editor| editor.enabled) {
+        return Ok(());
+    }
+    if !input.just_pressed(InputAction::Select) {
+        return Ok(());
+    }
+
+    let Some(hex_coord) = cursor.hex()? else {
+        return Ok(());
+    };
+
+    let start = match (debug.start, debug.goal) {
+        (Some(start), None) => start,
+        _ => {
+            debug.start = Some(hex_coord);
+            debug.goal = None;
+            debug.result = None;
+            return Ok(());
+        }
+    };
+
+    // Sans terrain généré, la grille est uniforme
+    let cost = |coord: &HexCoord| match cells.as_ref() {
+        Some(cells) => {
+            let terrain_type = overrides
+                .as_ref()
+                .and_then(|overrides| overrides.get(coord))
+                .or_else(|| cells.terrain_type(coord))?;
+            config.cost(coord, terrain_type)
+        }
+        None => (!config.blocked.contains(coord)).then_some(1.0),
+    };
+    let min_cost = if cells.is_some() { config.min_cost() } else { 1.0 };
+
+    let result = find_path(start, hex_coord, config.max_expansions, min_cost, cost);
+    match &result {
+        Ok(path) => info!(
+            "Chemin {:?} → {:?} : {} cellules, coût {:.1}",
+            start,
+            hex_coord,
+            path.coords.len(),
+            path.cost
+        ),
+        Err(err) => warn!("Pas de chemin {:?} → {:?} : {:?}", start, hex_coord, err),
+    }
+
+    debug.goal = Some(hex_coord);
+    debug.result = Some(result);
+    Ok(())
+}
+
+pub fn draw_path_debug(debug: Res<PathDebug>, hex_config: Res<HexConfig>, mut gizmos: Gizmos) {
+    if !debug.enabled {
+        return;
+    }
+
+    let world_pos = |coord: &HexCoord| hex_config.layout.hex_to_world_pos(coord.to_hex());
+    let marker_radius = hex_config.hex_radius * 0.4;
+
+    let color = match &debug.result {
+        Some(Err(_)) => Color::from(RED_500),
+        _ => Color::from(GREEN_400),
+    };
+
+    for endpoint in [debug.start, debug.goal].into_iter().flatten() {
+        gizmos.circle_2d(world_pos(&endpoint), marker_radius, color);
+    }
+
+    if let Some(Ok(path)) = &debug.result {
+        gizmos.linestrip_2d(path.coords.iter().map(world_pos), color);
+    }
+}
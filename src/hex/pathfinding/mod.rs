@@ -0,0 +1,8 @@
+pub mod astar;
+pub mod debug;
+pub mod pathfinding_config;
+pub mod plugin;
+
+pub use astar::{HexPath, PathError, find_path};
+pub use pathfinding_config::PathfindingConfig;
+pub use plugin::PathfindingPlugin;
@@ -0,0 +1,48 @@
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+
+use crate::hex::HexCoord;
+use crate::shared::types::TerrainType;
+
+/// Coûts de déplacement par terrain et cellules bloquées
+#[derive(Resource, Clone, Debug, Serialize, Deserialize)]
+pub struct PathfindingConfig {
+    // Coût pour entrer dans une cellule ; absent = infranchissable
+    pub costs: HashMap<TerrainType, f32>,
+    // Cellules infranchissables quel que soit leur terrain
+    pub blocked: HashSet<HexCoord>,
+    // Nombre maximal de cellules explorées par recherche
+    pub max_expansions: usize,
+}
+
+impl Default for PathfindingConfig {
+    fn default() -> Self {
+        Self {
+            costs: HashMap::from([
+                (TerrainType::Land, 1.0),
+                (TerrainType::Beach, 1.5),
+                (TerrainType::Cliff, 4.0),
+                (TerrainType::ShallowWater, 3.0),
+            ]),
+            blocked: HashSet::new(),
+            max_expansions: 50_000,
+        }
+    }
+}
+
+impl PathfindingConfig {
+    /// Coût d'entrée dans une cellule de ce terrain
+    pub fn cost(&self, coord: &HexCoord, terrain_type: TerrainType) -> Option<f32> {
+        if self.blocked.contains(coord) {
+            return None;
+        }
+        self.costs.get(&terrain_type).copied()
+    }
+
+    /// Plus petit coût franchissable (borne de l'heuristique A*)
+    pub fn min_cost(&self) -> f32 {
+        let min = self.costs.values().copied().fold(f32::INFINITY, f32::min);
+        if min.is_finite() { min.max(0.0) } else { 0.0 }
+    }
+}
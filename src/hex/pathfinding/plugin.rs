@@ -0,0 +1,22 @@
+use bevy::prelude::*;
+
+use super::PathfindingConfig;
+use super::debug;
+
+pub struct PathfindingPlugin;
+
+impl Plugin for PathfindingPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<PathfindingConfig>()
+            .init_resource::<debug::PathDebug>()
+            .add_systems(
+                Update,
+                (
+                    debug::toggle_path_debug,
+                    debug::pick_path_endpoints,
+                    debug::draw_path_debug,
+                )
+                    .chain(),
+            );
+    }
+}
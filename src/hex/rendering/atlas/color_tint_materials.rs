@@ -1,5 +1,6 @@
 use crate::hex::HexConfig;
 use crate::hex::rendering::mesh;
+use crate::shared::types::TerrainType;
 use bevy::color::palettes::tailwind::{CYAN_300, YELLOW_300};
 use bevy::prelude::*;
 use std::collections::HashMap;
@@ -32,12 +33,22 @@ impl ColorTintMaterials {
         material_map.insert("hover".to_string(), hover_material);
         material_map.insert("pressed".to_string(), pressed_material);
 
+        // Un matériau par type de terrain (tuiles peintes)
+        for terrain_type in TerrainType::ALL {
+            let terrain_material = materials.add(terrain_type.color());
+            material_map.insert(terrain_type.name().to_string(), terrain_material);
+        }
+
         Self {
             materials: material_map,
             hex_mesh,
         }
     }
 
+    pub fn terrain_material(&self, terrain_type: TerrainType) -> Handle<ColorMaterial> {
+        self.get_material(terrain_type.name().to_string())
+    }
+
     pub fn get_material(&self, name: String) -> Handle<ColorMaterial> {
         self.materials
             .get(&name)
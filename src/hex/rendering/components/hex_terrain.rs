@@ -0,0 +1,7 @@
+use bevy::prelude::*;
+
+use crate::shared::types::TerrainType;
+
+/// Terrain peint sur une tuile (matériau de base au lieu de "default")
+#[derive(Component, Clone, Copy, Debug)]
+pub struct HexTerrain(pub TerrainType);
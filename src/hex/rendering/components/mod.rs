@@ -1,3 +1,5 @@
+mod hex_terrain;
+pub use hex_terrain::*;
 mod hex_tile;
 pub use hex_tile::*;
 mod hex_visuals;
@@ -1,12 +1,17 @@
 use bevy::prelude::*;
 use hexx::{HexLayout, HexOrientation};
 
+// Rayon d'un hexagone du jeu, en unités monde
+pub const GAME_HEX_RADIUS: f32 = 48.0;
+
 /// Resource wrapper pour la configuration hexagonale
 #[derive(Resource, Clone)]
 pub struct HexConfig {
     pub layout: HexLayout,
     pub chunk_layout: HexLayout,
     pub hex_radius: f32,
+    pub orientation: HexOrientation,
+    pub ratio: Vec2,
     pub chunk_size: u8,
 }
 
@@ -16,7 +21,23 @@ impl Default for HexConfig {
     }
 }
 
+// Les layouts découlent des paramètres
+impl PartialEq for HexConfig {
+    fn eq(&self, other: &Self) -> bool {
+        self.hex_radius == other.hex_radius
+            && self.orientation == other.orientation
+            && self.ratio == other.ratio
+            && self.chunk_size == other.chunk_size
+    }
+}
+
 impl HexConfig {
+    /// Configuration du jeu, partagée par l'application et terrain_cli :
+    /// hexagones plats écrasés verticalement, chunks de 10
+    pub fn game(radius: f32) -> Self {
+        Self::new(radius, HexOrientation::Flat, Vec2::new(1.0, 0.67), 10)
+    }
+
     /// Crée une configuration avec un rayon donné
     pub fn new(radius: f32, orientation: HexOrientation, ratio: Vec2, chunk_size: u8) -> Self {
         let mut layout;
@@ -43,6 +64,8 @@ impl HexConfig {
             layout,
             chunk_layout,
             hex_radius: radius,
+            orientation,
+            ratio,
             chunk_size,
         }
     }
@@ -0,0 +1,82 @@
+use bevy::prelude::*;
+use noise::{NoiseFn, Perlin};
+
+use super::ContourConfig;
+
+/// Bruit fractal à domaine déformé pour les côtes.
+/// Les fréquences sont exprimées en cycles par hexagone, la géométrie
+/// ne dépend donc que de la graine et de la configuration.
+#[derive(Clone)]
+pub struct CoastlineNoise {
+    base: Perlin,
+    warp_x: Perlin,
+    warp_y: Perlin,
+    // Taille d'un hexagone en unités monde (normalise les coordonnées)
+    hex_size: Vec2,
+    frequency: f64,
+    octaves: usize,
+    lacunarity: f64,
+    persistence: f64,
+    warp_amplitude: f64,
+    warp_frequency: f64,
+}
+
+impl CoastlineNoise {
+    pub fn new(config: &ContourConfig, hex_size: Vec2) -> Self {
+        Self {
+            base: Perlin::new(config.seed),
+            warp_x: Perlin::new(config.seed.wrapping_add(1)),
+            warp_y: Perlin::new(config.seed.wrapping_add(2)),
+            hex_size: hex_size.max(Vec2::splat(f32::EPSILON)),
+            frequency: config.noise_frequency as f64,
+            octaves: config.noise_octaves,
+            lacunarity: config.noise_lacunarity as f64,
+            persistence: config.noise_persistence as f64,
+            warp_amplitude: config.warp_amplitude as f64,
+            warp_frequency: config.warp_frequency as f64,
+        }
+    }
+
+    /// Valeur du bruit à une position monde (environ dans [-1, 1])
+    pub fn sample(&self, world_pos: Vec2) -> f32 {
+        // Passer en coordonnées hexagonales (1.0 = un hexagone)
+        let p = world_pos / self.hex_size;
+        let (x, y) = (p.x as f64, p.y as f64);
+
+        // Déformation du domaine : décale le point d'échantillonnage selon
+        // deux autres champs de bruit, ce qui étire les côtes en fjords
+        let (x, y) = if self.warp_amplitude > 0.0 {
+            let wx = x * self.warp_frequency;
+            let wy = y * self.warp_frequency;
+            (
+                x + self.warp_amplitude * self.fbm(&self.warp_x, wx, wy, 3),
+                y + self.warp_amplitude * self.fbm(&self.warp_y, wx, wy, 3),
+            )
+        } else {
+            (x, y)
+        };
+
+        self.fbm(&self.base, x * self.frequency, y * self.frequency, self.octaves) as f32
+    }
+
+    // Somme fractale normalisée de plusieurs octaves
+    fn fbm(&self, noise: &Perlin, x: f64, y: f64, octaves: usize) -> f64 {
+        let mut value = 0.0;
+        let mut amplitude = 1.0;
+        let mut frequency = 1.0;
+        let mut total_amplitude = 0.0;
+
+        for _ in 0..octaves {
+            value += noise.get([x * frequency, y * frequency]) * amplitude;
+            total_amplitude += amplitude;
+            amplitude *= self.persistence;
+            frequency *= self.lacunarity;
+        }
+
+        if total_amplitude > 0.0 {
+            value / total_amplitude
+        } else {
+            0.0
+        }
+    }
+}
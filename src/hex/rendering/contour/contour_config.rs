@@ -1,27 +1,64 @@
+use serde::{Deserialize, Serialize};
+
+use super::ContourSmoothing;
+
 // Configuration du système de contours
-#[derive(Clone)]
+#[derive(Clone, Serialize, Deserialize)]
 pub struct ContourConfig {
     // Échelle : combien de pixels de la binary map par hexagone
     pub pixels_per_hex: f32,
     // Paramètres du bruit fractal
+    pub seed: u32,
     pub noise_amplitude: f32,
+    // Fréquence en cycles par hexagone
     pub noise_frequency: f32,
     pub noise_octaves: usize,
+    // Multiplicateur de fréquence entre deux octaves
+    pub noise_lacunarity: f32,
+    // Multiplicateur d'amplitude entre deux octaves
+    pub noise_persistence: f32,
+    // Déformation du domaine (en hexagones) et sa fréquence
+    pub warp_amplitude: f32,
+    pub warp_frequency: f32,
     // Seuil pour déterminer terre/mer (0.5 par défaut)
     pub threshold: f32,
     // Tension des splines (0.0 = linéaire, 1.0 = très courbé)
     pub spline_tension: f32,
+    // Stratégie de lissage, commune aux pipelines synchrone et asynchrone
+    pub smoothing: ContourSmoothing,
+    // Tolérance de simplification en pixels écran (Douglas-Peucker)
+    pub lod_pixel_tolerance: f32,
+    // Échelles orthographiques maximales de chaque niveau de détail
+    pub lod_scales: Vec<f32>,
+    // Iso-contours : résolution de la grille et niveaux (en hexagones depuis la côte)
+    pub iso_grid_size: usize,
+    pub depth_levels: Vec<f32>,
+    pub elevation_levels: Vec<f32>,
+    // Épaisseur des lignes d'iso-contours (unités monde)
+    pub iso_line_width: f32,
 }
 
 impl Default for ContourConfig {
     fn default() -> Self {
         Self {
             pixels_per_hex: 0.25, // 1 hex = 1/4 pixel (4 hex par pixel)
+            seed: 42,
             noise_amplitude: 0.3,
-            noise_frequency: 2.0,
+            noise_frequency: 0.15,
             noise_octaves: 3,
+            noise_lacunarity: 2.0,
+            noise_persistence: 0.5,
+            warp_amplitude: 4.0,
+            warp_frequency: 0.05,
             threshold: 0.5,
             spline_tension: 0.5,
+            smoothing: ContourSmoothing::default(),
+            lod_pixel_tolerance: 1.0,
+            lod_scales: vec![1.0, 2.0, 5.0],
+            iso_grid_size: 400,
+            depth_levels: vec![-2.0, -5.0, -10.0],
+            elevation_levels: vec![3.0, 6.0, 10.0],
+            iso_line_width: 2.0,
         }
     }
 }
\ No newline at end of file
@@ -0,0 +1,71 @@
+use bevy::prelude::*;
+
+use super::terrain::TerrainMeshData;
+use crate::camera::MainCamera;
+
+/// Niveau de détail d'un mesh de contours
+#[derive(Clone)]
+pub struct ContourLodLevel {
+    // Échelle orthographique maximale pour laquelle ce niveau est utilisé
+    pub max_scale: f32,
+    pub mesh: Handle<Mesh>,
+}
+
+/// Meshes de contours pré-calculés à plusieurs niveaux de simplification
+#[derive(Component, Clone)]
+pub struct ContourLod {
+    // Triés par max_scale croissant
+    pub levels: Vec<ContourLodLevel>,
+}
+
+impl ContourLod {
+    /// Crée les assets de chaque niveau à partir des données (échelle max, mesh)
+    pub fn from_mesh_data(levels: &[(f32, TerrainMeshData)], meshes: &mut Assets<Mesh>) -> Self {
+        let mut levels: Vec<ContourLodLevel> = levels
+            .iter()
+            .map(|(max_scale, data)| ContourLodLevel {
+                max_scale: *max_scale,
+                mesh: meshes.add(data.clone().into_mesh()),
+            })
+            .collect();
+        levels.sort_by(|a, b| a.max_scale.total_cmp(&b.max_scale));
+
+        Self { levels }
+    }
+
+    /// Choisit le mesh adapté à l'échelle courante de la caméra
+    pub fn mesh_for_scale(&self, scale: f32) -> Option<&Handle<Mesh>> {
+        self.levels
+            .iter()
+            .find(|level| scale <= level.max_scale)
+            .or_else(|| self.levels.last())
+            .map(|level| &level.mesh)
+    }
+}
+
+/// Échange les meshes de contours selon le zoom de la MainCamera. Un
+/// `ContourLod` qui vient d'être spawné (génération, régénération) reçoit
+/// aussi le niveau du zoom courant.
+pub fn update_contour_lod(
+    camera: Query<Ref<Projection>, With<MainCamera>>,
+    mut contours: Query<(Ref<ContourLod>, &mut Mesh2d)>,
+) {
+    let Ok(projection) = camera.single() else {
+        return;
+    };
+    let Projection::Orthographic(ortho) = &*projection else {
+        return;
+    };
+    let zoom_changed = projection.is_changed();
+
+    for (lod, mut mesh) in &mut contours {
+        if !zoom_changed && !lod.is_added() {
+            continue;
+        }
+        if let Some(handle) = lod.mesh_for_scale(ortho.scale)
+            && mesh.0 != *handle
+        {
+            mesh.0 = handle.clone();
+        }
+    }
+}
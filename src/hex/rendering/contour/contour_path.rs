@@ -1,7 +1,106 @@
 use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct ContourPath {
     pub points: Vec<Vec2>,
     pub is_closed: bool,
-}
\ No newline at end of file
+}
+
+impl ContourPath {
+    /// Simplifie le contour avec Douglas-Peucker (tolérance en unités monde)
+    pub fn simplify(&self, tolerance: f32) -> ContourPath {
+        let min_points = if self.is_closed { 3 } else { 2 };
+        if tolerance <= 0.0 || self.points.len() <= min_points {
+            return self.clone();
+        }
+
+        let points = if self.is_closed {
+            // Couper le contour fermé au point le plus éloigné du premier,
+            // puis simplifier les deux moitiés indépendamment
+            let far = self
+                .points
+                .iter()
+                .enumerate()
+                .max_by(|(_, a), (_, b)| {
+                    a.distance_squared(self.points[0])
+                        .total_cmp(&b.distance_squared(self.points[0]))
+                })
+                .map(|(i, _)| i)
+                .unwrap_or(0);
+
+            let mut first_half = douglas_peucker(&self.points[..=far], tolerance);
+            let mut second_half: Vec<Vec2> = self.points[far..].to_vec();
+            second_half.push(self.points[0]);
+            let second_half = douglas_peucker(&second_half, tolerance);
+
+            first_half.pop();
+            first_half.extend(&second_half[..second_half.len() - 1]);
+            first_half
+        } else {
+            douglas_peucker(&self.points, tolerance)
+        };
+
+        if points.len() < min_points {
+            return self.clone();
+        }
+
+        ContourPath {
+            points,
+            is_closed: self.is_closed,
+        }
+    }
+}
+
+// Douglas-Peucker itératif (évite la récursion sur les longues côtes)
+fn douglas_peucker(points: &[Vec2], tolerance: f32) -> Vec<Vec2> {
+    if points.len() < 3 {
+        return points.to_vec();
+    }
+
+    let mut keep = vec![false; points.len()];
+    keep[0] = true;
+    keep[points.len() - 1] = true;
+
+    let mut stack = vec![(0, points.len() - 1)];
+    let tolerance_sq = tolerance * tolerance;
+
+    while let Some((start, end)) = stack.pop() {
+        if end <= start + 1 {
+            continue;
+        }
+
+        let mut max_dist_sq = 0.0;
+        let mut max_index = start;
+        for i in start + 1..end {
+            let dist_sq = segment_distance_squared(points[i], points[start], points[end]);
+            if dist_sq > max_dist_sq {
+                max_dist_sq = dist_sq;
+                max_index = i;
+            }
+        }
+
+        if max_dist_sq > tolerance_sq {
+            keep[max_index] = true;
+            stack.push((start, max_index));
+            stack.push((max_index, end));
+        }
+    }
+
+    points
+        .iter()
+        .zip(keep)
+        .filter_map(|(point, kept)| kept.then_some(*point))
+        .collect()
+}
+
+// Distance au carré entre un point et un segment
+fn segment_distance_squared(p: Vec2, a: Vec2, b: Vec2) -> f32 {
+    let ab = b - a;
+    let length_sq = ab.length_squared();
+    if length_sq == 0.0 {
+        return p.distance_squared(a);
+    }
+    let t = ((p - a).dot(ab) / length_sq).clamp(0.0, 1.0);
+    p.distance_squared(a + ab * t)
+}
@@ -0,0 +1,178 @@
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use super::ContourPath;
+
+/// Stratégie de lissage appliquée aux contours extraits
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub enum ContourSmoothing {
+    // Aucun lissage, les points du marching squares sont conservés
+    None,
+    // Découpe des coins de Chaikin (chaque itération double les points)
+    Chaikin { iterations: u32 },
+    // Catmull-Rom centripète : pas de boucles ni de rebroussements
+    CentripetalCatmullRom { segments: u32 },
+    // Catmull-Rom centripète, subdivisé selon la courbure locale
+    Adaptive {
+        max_segments: u32,
+        // Angle (radians) de virage couvert par un segment
+        angle_per_segment: f32,
+    },
+}
+
+impl Default for ContourSmoothing {
+    fn default() -> Self {
+        ContourSmoothing::CentripetalCatmullRom { segments: 5 }
+    }
+}
+
+impl ContourPath {
+    /// Lisse le contour. `tension` module les tangentes des splines
+    /// (0.5 = Catmull-Rom standard, 0.0 = linéaire).
+    pub fn smooth(&self, smoothing: ContourSmoothing, tension: f32) -> ContourPath {
+        let min_points = if self.is_closed { 3 } else { 2 };
+        if self.points.len() <= min_points {
+            return self.clone();
+        }
+
+        let points = match smoothing {
+            ContourSmoothing::None => return self.clone(),
+            ContourSmoothing::Chaikin { iterations } => {
+                let mut points = self.points.clone();
+                for _ in 0..iterations {
+                    points = chaikin(&points, self.is_closed);
+                }
+                points
+            }
+            ContourSmoothing::CentripetalCatmullRom { segments } => {
+                self.centripetal_spline(tension, |_| segments.max(1))
+            }
+            ContourSmoothing::Adaptive {
+                max_segments,
+                angle_per_segment,
+            } => self.centripetal_spline(tension, |turn| {
+                let needed = (turn / angle_per_segment.max(f32::EPSILON)).ceil() as u32;
+                needed.clamp(1, max_segments.max(1))
+            }),
+        };
+
+        ContourPath {
+            points,
+            is_closed: self.is_closed,
+        }
+    }
+
+    // Échantillonne une spline Catmull-Rom centripète. `segments_for` reçoit
+    // l'angle de virage (radians) autour du segment et renvoie sa subdivision.
+    fn centripetal_spline(&self, tension: f32, segments_for: impl Fn(f32) -> u32) -> Vec<Vec2> {
+        let points = &self.points;
+        let n = points.len();
+        let segment_count = if self.is_closed { n } else { n - 1 };
+
+        // Les chemins ouverts sont prolongés par des points fantômes réfléchis
+        // au lieu d'être refermés sur eux-mêmes
+        let point_at = |i: isize| -> Vec2 {
+            if self.is_closed {
+                points[i.rem_euclid(n as isize) as usize]
+            } else if i < 0 {
+                points[0] * 2.0 - points[1]
+            } else if i as usize >= n {
+                points[n - 1] * 2.0 - points[n - 2]
+            } else {
+                points[i as usize]
+            }
+        };
+
+        let mut smoothed = Vec::new();
+
+        for i in 0..segment_count as isize {
+            let p0 = point_at(i - 1);
+            let p1 = point_at(i);
+            let p2 = point_at(i + 1);
+            let p3 = point_at(i + 2);
+
+            let turn = turning_angle(p0, p1, p2) + turning_angle(p1, p2, p3);
+            let segments = segments_for(turn);
+
+            for j in 0..segments {
+                let t = j as f32 / segments as f32;
+                smoothed.push(centripetal_catmull_rom(p0, p1, p2, p3, t, tension));
+            }
+        }
+
+        if !self.is_closed {
+            smoothed.push(points[n - 1]);
+        }
+
+        smoothed
+    }
+}
+
+// Une itération de Chaikin. Les extrémités d'un chemin ouvert sont conservées.
+fn chaikin(points: &[Vec2], is_closed: bool) -> Vec<Vec2> {
+    let n = points.len();
+    let mut result = Vec::with_capacity(n * 2);
+
+    if !is_closed {
+        result.push(points[0]);
+    }
+
+    let edge_count = if is_closed { n } else { n - 1 };
+    for i in 0..edge_count {
+        let a = points[i];
+        let b = points[(i + 1) % n];
+        let first_edge = !is_closed && i == 0;
+        let last_edge = !is_closed && i == edge_count - 1;
+
+        if !first_edge {
+            result.push(a.lerp(b, 0.25));
+        }
+        if !last_edge {
+            result.push(a.lerp(b, 0.75));
+        }
+    }
+
+    if !is_closed {
+        result.push(points[n - 1]);
+    }
+
+    result
+}
+
+// Angle de virage au point b (0 = ligne droite)
+fn turning_angle(a: Vec2, b: Vec2, c: Vec2) -> f32 {
+    let d1 = b - a;
+    let d2 = c - b;
+    if d1.length_squared() == 0.0 || d2.length_squared() == 0.0 {
+        return 0.0;
+    }
+    d1.angle_to(d2).abs()
+}
+
+// Point sur une spline de Catmull-Rom centripète (alpha = 0.5), exprimée
+// sous forme de Hermite entre p1 et p2
+pub fn centripetal_catmull_rom(p0: Vec2, p1: Vec2, p2: Vec2, p3: Vec2, t: f32, tension: f32) -> Vec2 {
+    // Paramétrisation centripète : dt = |Δp|^0.5
+    let knot = |a: Vec2, b: Vec2| a.distance(b).sqrt().max(1e-4);
+    let dt0 = knot(p0, p1);
+    let dt1 = knot(p1, p2);
+    let dt2 = knot(p2, p3);
+
+    // Tangentes non uniformes, ramenées à l'intervalle [p1, p2]
+    let m1 = ((p1 - p0) / dt0 - (p2 - p0) / (dt0 + dt1) + (p2 - p1) / dt1) * dt1;
+    let m2 = ((p2 - p1) / dt1 - (p3 - p1) / (dt1 + dt2) + (p3 - p2) / dt2) * dt1;
+
+    // tension 0.5 correspond à la spline standard
+    let m1 = m1 * tension * 2.0;
+    let m2 = m2 * tension * 2.0;
+
+    let t2 = t * t;
+    let t3 = t2 * t;
+
+    let h00 = 2.0 * t3 - 3.0 * t2 + 1.0;
+    let h10 = t3 - 2.0 * t2 + t;
+    let h01 = -2.0 * t3 + 3.0 * t2;
+    let h11 = t3 - t2;
+
+    p1 * h00 + m1 * h10 + p2 * h01 + m2 * h11
+}
@@ -0,0 +1,276 @@
+use std::fmt::Write as _;
+use std::fs;
+use std::io;
+use std::path::Path;
+
+use super::super::terrain::TerrainMeshData;
+
+// Constantes glTF 2.0
+const COMPONENT_FLOAT: u32 = 5126;
+const COMPONENT_UNSIGNED_INT: u32 = 5125;
+const TARGET_ARRAY_BUFFER: u32 = 34962;
+const TARGET_ELEMENT_ARRAY_BUFFER: u32 = 34963;
+const GLB_MAGIC: u32 = 0x4654_6C67; // "glTF"
+const CHUNK_JSON: u32 = 0x4E4F_534A; // "JSON"
+const CHUNK_BIN: u32 = 0x004E_4942; // "BIN\0"
+
+/// Écrit les meshes dans un fichier glTF binaire (.glb), un nœud par mesh.
+/// Attributs exportés : POSITION, NORMAL, TEXCOORD_0, COLOR_0 et indices u32.
+pub fn export_glb(path: impl AsRef<Path>, meshes: &[(&str, &TerrainMeshData)]) -> io::Result<()> {
+    let path = path.as_ref();
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    fs::write(path, write_glb(meshes))
+}
+
+/// Sérialise les meshes au format GLB
+pub fn write_glb(meshes: &[(&str, &TerrainMeshData)]) -> Vec<u8> {
+    let mut bin = Vec::new();
+    let mut buffer_views = Vec::new();
+    let mut accessors = Vec::new();
+    let mut gltf_meshes = Vec::new();
+    let mut nodes = Vec::new();
+
+    // Ajoute un bufferView + accessor, renvoie l'index de l'accessor
+    let mut push_accessor = |bin: &mut Vec<u8>,
+                             bytes: Vec<u8>,
+                             count: usize,
+                             component_type: u32,
+                             kind: &str,
+                             target: u32,
+                             bounds: Option<([f32; 3], [f32; 3])>| {
+        // Alignement sur 4 octets exigé par la spec
+        while !bin.len().is_multiple_of(4) {
+            bin.push(0);
+        }
+        let offset = bin.len();
+        bin.extend_from_slice(&bytes);
+
+        buffer_views.push(format!(
+            r#"{{"buffer":0,"byteOffset":{},"byteLength":{},"target":{}}}"#,
+            offset,
+            bytes.len(),
+            target
+        ));
+
+        let mut accessor = format!(
+            r#"{{"bufferView":{},"componentType":{},"count":{},"type":"{}""#,
+            buffer_views.len() - 1,
+            component_type,
+            count,
+            kind
+        );
+        if let Some((min, max)) = bounds {
+            let _ = write!(
+                accessor,
+                r#","min":[{},{},{}],"max":[{},{},{}]"#,
+                min[0], min[1], min[2], max[0], max[1], max[2]
+            );
+        }
+        accessor.push('}');
+        accessors.push(accessor);
+        accessors.len() - 1
+    };
+
+    for (name, data) in meshes {
+        if data.vertices.is_empty() || data.indices.is_empty() {
+            continue;
+        }
+
+        let count = data.vertices.len();
+        let mut min = [f32::MAX; 3];
+        let mut max = [f32::MIN; 3];
+        for vertex in &data.vertices {
+            for axis in 0..3 {
+                min[axis] = min[axis].min(vertex[axis]);
+                max[axis] = max[axis].max(vertex[axis]);
+            }
+        }
+
+        let position = push_accessor(
+            &mut bin,
+            f32_bytes(data.vertices.iter().flatten()),
+            count,
+            COMPONENT_FLOAT,
+            "VEC3",
+            TARGET_ARRAY_BUFFER,
+            Some((min, max)),
+        );
+
+        let mut attributes = format!(r#""POSITION":{}"#, position);
+
+        if data.normals.len() == count {
+            let normal = push_accessor(
+                &mut bin,
+                f32_bytes(data.normals.iter().flatten()),
+                count,
+                COMPONENT_FLOAT,
+                "VEC3",
+                TARGET_ARRAY_BUFFER,
+                None,
+            );
+            let _ = write!(attributes, r#","NORMAL":{}"#, normal);
+        }
+        if data.uvs.len() == count {
+            // glTF place l'origine des UVs en haut à gauche
+            let uv = push_accessor(
+                &mut bin,
+                f32_bytes(data.uvs.iter().flat_map(|uv| [uv[0], 1.0 - uv[1]])),
+                count,
+                COMPONENT_FLOAT,
+                "VEC2",
+                TARGET_ARRAY_BUFFER,
+                None,
+            );
+            let _ = write!(attributes, r#","TEXCOORD_0":{}"#, uv);
+        }
+        if data.colors.len() == count {
+            let color = push_accessor(
+                &mut bin,
+                f32_bytes(data.colors.iter().flatten()),
+                count,
+                COMPONENT_FLOAT,
+                "VEC4",
+                TARGET_ARRAY_BUFFER,
+                None,
+            );
+            let _ = write!(attributes, r#","COLOR_0":{}"#, color);
+        }
+
+        let indices = push_accessor(
+            &mut bin,
+            data.indices.iter().flat_map(|i| i.to_le_bytes()).collect(),
+            data.indices.len(),
+            COMPONENT_UNSIGNED_INT,
+            "SCALAR",
+            TARGET_ELEMENT_ARRAY_BUFFER,
+            None,
+        );
+
+        gltf_meshes.push(format!(
+            r#"{{"name":"{}","primitives":[{{"attributes":{{{}}},"indices":{},"mode":4}}]}}"#,
+            name, attributes, indices
+        ));
+        nodes.push(format!(
+            r#"{{"name":"{}","mesh":{}}}"#,
+            name,
+            gltf_meshes.len() - 1
+        ));
+    }
+
+    while bin.len() % 4 != 0 {
+        bin.push(0);
+    }
+
+    let node_indices: Vec<String> = (0..nodes.len()).map(|i| i.to_string()).collect();
+    let mut json = format!(
+        r#"{{"asset":{{"version":"2.0","generator":"playground"}},"scene":0,"scenes":[{{"nodes":[{}]}}],"nodes":[{}],"meshes":[{}],"accessors":[{}],"bufferViews":[{}],"buffers":[{{"byteLength":{}}}]}}"#,
+        node_indices.join(","),
+        nodes.join(","),
+        gltf_meshes.join(","),
+        accessors.join(","),
+        buffer_views.join(","),
+        bin.len()
+    );
+    // Le chunk JSON est complété par des espaces
+    while json.len() % 4 != 0 {
+        json.push(' ');
+    }
+
+    let total_length = 12 + 8 + json.len() + 8 + bin.len();
+    let mut glb = Vec::with_capacity(total_length);
+    glb.extend_from_slice(&GLB_MAGIC.to_le_bytes());
+    glb.extend_from_slice(&2u32.to_le_bytes());
+    glb.extend_from_slice(&(total_length as u32).to_le_bytes());
+
+    glb.extend_from_slice(&(json.len() as u32).to_le_bytes());
+    glb.extend_from_slice(&CHUNK_JSON.to_le_bytes());
+    glb.extend_from_slice(json.as_bytes());
+
+    glb.extend_from_slice(&(bin.len() as u32).to_le_bytes());
+    glb.extend_from_slice(&CHUNK_BIN.to_le_bytes());
+    glb.extend_from_slice(&bin);
+
+    glb
+}
+
+fn f32_bytes(values: impl IntoIterator<Item = impl std::borrow::Borrow<f32>>) -> Vec<u8> {
+    values
+        .into_iter()
+        .flat_map(|v| v.borrow().to_le_bytes())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn read_u32(bytes: &[u8], offset: usize) -> u32 {
+        u32::from_le_bytes(bytes[offset..offset + 4].try_into().unwrap())
+    }
+
+    // Un quad : 4 sommets, 2 triangles, sans couleurs
+    fn quad() -> TerrainMeshData {
+        TerrainMeshData {
+            vertices: vec![
+                [0.0, 0.0, 0.0],
+                [2.0, 0.0, 0.0],
+                [2.0, 1.0, 0.0],
+                [0.0, 1.0, 0.0],
+            ],
+            indices: vec![0, 1, 2, 0, 2, 3],
+            normals: vec![[0.0, 0.0, 1.0]; 4],
+            uvs: vec![[0.0, 0.0], [1.0, 0.0], [1.0, 1.0], [0.0, 1.0]],
+            colors: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn header_and_chunks_are_aligned() {
+        let data = quad();
+        let glb = write_glb(&[("land", &data), ("empty", &TerrainMeshData::default())]);
+
+        assert_eq!(read_u32(&glb, 0), GLB_MAGIC);
+        assert_eq!(read_u32(&glb, 4), 2);
+        assert_eq!(read_u32(&glb, 8) as usize, glb.len());
+
+        let json_length = read_u32(&glb, 12) as usize;
+        assert_eq!(read_u32(&glb, 16), CHUNK_JSON);
+        assert_eq!(json_length % 4, 0);
+
+        let bin_header = 20 + json_length;
+        let bin_length = read_u32(&glb, bin_header) as usize;
+        assert_eq!(read_u32(&glb, bin_header + 4), CHUNK_BIN);
+        assert_eq!(bin_length % 4, 0);
+        assert_eq!(bin_header + 8 + bin_length, glb.len());
+
+        // Positions 48 + normales 48 + UVs 32 + indices 24 octets
+        assert_eq!(bin_length, 152);
+    }
+
+    #[test]
+    fn accessors_match_mesh_data() {
+        let data = quad();
+        let glb = write_glb(&[("land", &data), ("empty", &TerrainMeshData::default())]);
+        let json_length = read_u32(&glb, 12) as usize;
+        let json = std::str::from_utf8(&glb[20..20 + json_length]).unwrap();
+
+        // Le mesh vide n'est pas exporté
+        assert!(json.contains(r#""nodes":[{"name":"land","mesh":0}]"#));
+        assert!(!json.contains("empty"));
+        assert!(json.contains(
+            r#""attributes":{"POSITION":0,"NORMAL":1,"TEXCOORD_0":2},"indices":3"#
+        ));
+        assert!(!json.contains("COLOR_0"));
+
+        assert_eq!(json.matches(r#""count":4,"type":"VEC3""#).count(), 2);
+        assert!(json.contains(r#""count":4,"type":"VEC2""#));
+        assert!(json.contains(&format!(
+            r#""componentType":{},"count":6,"type":"SCALAR""#,
+            COMPONENT_UNSIGNED_INT
+        )));
+        assert!(json.contains(r#""min":[0,0,0],"max":[2,1,0]"#));
+        assert!(json.contains(r#""buffers":[{"byteLength":152}]"#));
+    }
+}
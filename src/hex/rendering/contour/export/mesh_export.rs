@@ -0,0 +1,68 @@
+use bevy::prelude::*;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use super::super::terrain::TerrainMeshes;
+use super::{export_glb, export_obj};
+
+/// Format de sortie des meshes de terrain
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MeshExportFormat {
+    // glTF binaire (.glb), rechargeable par l'AssetServer
+    Gltf,
+    // Wavefront OBJ, format de repli pour les outils 3D
+    Obj,
+}
+
+impl MeshExportFormat {
+    pub fn extension(&self) -> &'static str {
+        match self {
+            MeshExportFormat::Gltf => "glb",
+            MeshExportFormat::Obj => "obj",
+        }
+    }
+}
+
+/// Options de l'export des meshes de terrain
+#[derive(Resource, Clone)]
+pub struct MeshExportOptions {
+    // Chemin sans extension, complété selon le format
+    pub output_path: PathBuf,
+    pub formats: Vec<MeshExportFormat>,
+}
+
+impl Default for MeshExportOptions {
+    fn default() -> Self {
+        Self {
+            output_path: PathBuf::from("exports/terrain"),
+            formats: vec![MeshExportFormat::Gltf, MeshExportFormat::Obj],
+        }
+    }
+}
+
+/// Exporte la terre, les lacs, les bandes d'eau et le trait de côte.
+/// Renvoie les fichiers écrits.
+pub fn export_meshes(
+    output_path: impl AsRef<Path>,
+    meshes: &TerrainMeshes,
+    formats: &[MeshExportFormat],
+) -> io::Result<Vec<PathBuf>> {
+    let named = meshes.named();
+    for (name, data) in &named {
+        data.validate().map_err(|err| {
+            io::Error::new(io::ErrorKind::InvalidData, format!("mesh {name} : {err}"))
+        })?;
+    }
+    let mut written = Vec::new();
+
+    for format in formats {
+        let path = output_path.as_ref().with_extension(format.extension());
+        match format {
+            MeshExportFormat::Gltf => export_glb(&path, &named)?,
+            MeshExportFormat::Obj => export_obj(&path, &named)?,
+        }
+        written.push(path);
+    }
+
+    Ok(written)
+}
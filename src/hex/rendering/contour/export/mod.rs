@@ -0,0 +1,10 @@
+mod gltf;
+mod mesh_export;
+mod obj;
+mod svg;
+pub mod systems;
+
+pub use gltf::{export_glb, write_glb};
+pub use mesh_export::{MeshExportFormat, MeshExportOptions, export_meshes};
+pub use obj::{export_obj, write_obj};
+pub use svg::{SvgExportOptions, SvgLayer, contour_layers, export_svg, write_svg};
@@ -0,0 +1,76 @@
+use std::fmt::Write as _;
+use std::fs;
+use std::io;
+use std::path::Path;
+
+use super::super::terrain::TerrainMeshData;
+
+/// Écrit les meshes au format Wavefront OBJ, un objet par mesh.
+/// Les couleurs de vertex suivent l'extension courante `v x y z r g b`.
+pub fn export_obj(path: impl AsRef<Path>, meshes: &[(&str, &TerrainMeshData)]) -> io::Result<()> {
+    let path = path.as_ref();
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    fs::write(path, write_obj(meshes))
+}
+
+/// Sérialise les meshes au format OBJ
+pub fn write_obj(meshes: &[(&str, &TerrainMeshData)]) -> String {
+    let mut obj = String::from("# playground terrain export\n");
+    // Les indices OBJ sont globaux au fichier et commencent à 1
+    let mut offset = 1;
+
+    for (name, data) in meshes {
+        if data.vertices.is_empty() {
+            continue;
+        }
+        let count = data.vertices.len();
+        let has_uvs = data.uvs.len() == count;
+        let has_normals = data.normals.len() == count;
+        let has_colors = data.colors.len() == count;
+
+        let _ = writeln!(obj, "o {}", name);
+
+        for (i, v) in data.vertices.iter().enumerate() {
+            if has_colors {
+                let c = data.colors[i];
+                let _ = writeln!(
+                    obj,
+                    "v {} {} {} {} {} {}",
+                    v[0], v[1], v[2], c[0], c[1], c[2]
+                );
+            } else {
+                let _ = writeln!(obj, "v {} {} {}", v[0], v[1], v[2]);
+            }
+        }
+        if has_uvs {
+            for uv in &data.uvs {
+                let _ = writeln!(obj, "vt {} {}", uv[0], uv[1]);
+            }
+        }
+        if has_normals {
+            for n in &data.normals {
+                let _ = writeln!(obj, "vn {} {} {}", n[0], n[1], n[2]);
+            }
+        }
+
+        for triangle in data.indices.chunks_exact(3) {
+            obj.push('f');
+            for &index in triangle {
+                let i = index as usize + offset;
+                let _ = match (has_uvs, has_normals) {
+                    (true, true) => write!(obj, " {0}/{0}/{0}", i),
+                    (true, false) => write!(obj, " {0}/{0}", i),
+                    (false, true) => write!(obj, " {0}//{0}", i),
+                    (false, false) => write!(obj, " {}", i),
+                };
+            }
+            obj.push('\n');
+        }
+
+        offset += count;
+    }
+
+    obj
+}
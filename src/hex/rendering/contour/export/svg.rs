@@ -0,0 +1,232 @@
+use bevy::prelude::*;
+use std::fmt::Write as _;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use super::super::{ContourPath, IsoContourKind, TerrainContours};
+
+/// Options de l'export SVG des contours
+#[derive(Resource, Clone)]
+pub struct SvgExportOptions {
+    pub output_path: PathBuf,
+    // Contours lissés (true) ou bruts du marching squares (false)
+    pub smoothed: bool,
+    // Épaisseur des traits en unités monde
+    pub stroke_width: f32,
+    // Marge autour des contours en unités monde
+    pub margin: f32,
+}
+
+impl Default for SvgExportOptions {
+    fn default() -> Self {
+        Self {
+            output_path: PathBuf::from("exports/coastline.svg"),
+            smoothed: true,
+            stroke_width: 2.0,
+            margin: 50.0,
+        }
+    }
+}
+
+/// Un calque SVG (<g>) regroupant les chemins d'une même bande de terrain
+#[derive(Clone, Debug)]
+pub struct SvgLayer {
+    pub name: String,
+    pub paths: Vec<ContourPath>,
+    pub stroke: String,
+    pub fill: Option<String>,
+}
+
+/// Construit un calque pour la côte puis un calque par niveau d'iso-contour
+pub fn contour_layers(contours: &TerrainContours, smoothed: bool) -> Vec<SvgLayer> {
+    let mut layers = vec![SvgLayer {
+        name: "coastline".to_string(),
+        paths: contours.coastline(smoothed).to_vec(),
+        stroke: "#5a4630".to_string(),
+        fill: Some("#ccb080".to_string()),
+    }];
+
+    // Un calque par (famille, niveau), dans l'ordre d'extraction
+    for iso in contours.iso_contours(smoothed) {
+        let (prefix, stroke) = match iso.kind {
+            IsoContourKind::Depth => ("depth", "#264d99"),
+            IsoContourKind::Elevation => ("elevation", "#734d26"),
+        };
+        let name = format!("{}_{}", prefix, iso.level);
+
+        match layers.iter_mut().find(|layer| layer.name == name) {
+            Some(layer) => layer.paths.push(iso.path.clone()),
+            None => layers.push(SvgLayer {
+                name,
+                paths: vec![iso.path.clone()],
+                stroke: stroke.to_string(),
+                fill: None,
+            }),
+        }
+    }
+
+    layers
+}
+
+/// Écrit les calques dans un fichier SVG (dossiers parents créés au besoin)
+pub fn export_svg(
+    path: impl AsRef<Path>,
+    layers: &[SvgLayer],
+    options: &SvgExportOptions,
+) -> io::Result<()> {
+    let path = path.as_ref();
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    fs::write(path, write_svg(layers, options))
+}
+
+/// Sérialise les calques en document SVG. Les coordonnées monde (y vers le haut)
+/// sont ramenées dans la viewBox (y vers le bas).
+pub fn write_svg(layers: &[SvgLayer], options: &SvgExportOptions) -> String {
+    let mut min = Vec2::splat(f32::MAX);
+    let mut max = Vec2::splat(f32::MIN);
+    for point in layers
+        .iter()
+        .flat_map(|layer| &layer.paths)
+        .flat_map(|path| &path.points)
+    {
+        min = min.min(*point);
+        max = max.max(*point);
+    }
+    if min.x > max.x {
+        min = Vec2::ZERO;
+        max = Vec2::ZERO;
+    }
+    min -= Vec2::splat(options.margin);
+    max += Vec2::splat(options.margin);
+    let size = max - min;
+
+    let to_svg = |p: Vec2| Vec2::new(p.x - min.x, max.y - p.y);
+
+    let mut svg = String::new();
+    let _ = writeln!(svg, r#"<?xml version="1.0" encoding="UTF-8"?>"#);
+    let _ = writeln!(
+        svg,
+        r#"<svg xmlns="http://www.w3.org/2000/svg" xmlns:inkscape="http://www.inkscape.org/namespaces/inkscape" viewBox="0 0 {:.2} {:.2}" width="{:.0}" height="{:.0}">"#,
+        size.x, size.y, size.x, size.y
+    );
+
+    for layer in layers {
+        let _ = writeln!(
+            svg,
+            r#"  <g id="{0}" inkscape:groupmode="layer" inkscape:label="{0}" stroke="{1}" stroke-width="{2}" fill="{3}" fill-rule="evenodd" stroke-linejoin="round">"#,
+            layer.name,
+            layer.stroke,
+            options.stroke_width,
+            layer.fill.as_deref().unwrap_or("none"),
+        );
+
+        for path in &layer.paths {
+            let Some((first, rest)) = path.points.split_first() else {
+                continue;
+            };
+
+            let first = to_svg(*first);
+            let mut d = format!("M{:.2},{:.2}", first.x, first.y);
+            for point in rest {
+                let point = to_svg(*point);
+                let _ = write!(d, " L{:.2},{:.2}", point.x, point.y);
+            }
+            if path.is_closed {
+                d.push_str(" Z");
+            }
+
+            // Un chemin ouvert n'est jamais rempli
+            if path.is_closed {
+                let _ = writeln!(svg, r#"    <path d="{}"/>"#, d);
+            } else {
+                let _ = writeln!(svg, r#"    <path d="{}" fill="none"/>"#, d);
+            }
+        }
+
+        let _ = writeln!(svg, "  </g>");
+    }
+
+    let _ = writeln!(svg, "</svg>");
+    svg
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::super::IsoContour;
+    use super::*;
+
+    fn path(points: &[(f32, f32)], is_closed: bool) -> ContourPath {
+        ContourPath {
+            points: points.iter().map(|&(x, y)| Vec2::new(x, y)).collect(),
+            is_closed,
+        }
+    }
+
+    fn iso(level: f32, kind: IsoContourKind, path: ContourPath) -> IsoContour {
+        IsoContour { level, kind, path }
+    }
+
+    // Carré lissé décalé d'une unité par rapport au carré brut
+    fn contours() -> TerrainContours {
+        let raw = path(&[(0.0, 0.0), (100.0, 0.0), (100.0, 50.0), (0.0, 50.0)], true);
+        let smoothed = path(&[(1.0, 1.0), (99.0, 1.0), (99.0, 49.0), (1.0, 49.0)], true);
+        let iso_contours = |coast: &ContourPath| {
+            vec![
+                iso(-1.0, IsoContourKind::Depth, coast.clone()),
+                iso(-1.0, IsoContourKind::Depth, path(&[(10.0, 10.0), (20.0, 10.0)], false)),
+                iso(-2.0, IsoContourKind::Depth, coast.clone()),
+                iso(1.0, IsoContourKind::Elevation, coast.clone()),
+            ]
+        };
+        TerrainContours {
+            raw_iso_contours: iso_contours(&raw),
+            iso_contours: iso_contours(&smoothed),
+            raw_coastline: vec![raw],
+            coastline: vec![smoothed],
+        }
+    }
+
+    fn options() -> SvgExportOptions {
+        SvgExportOptions {
+            margin: 10.0,
+            ..default()
+        }
+    }
+
+    #[test]
+    fn view_box_covers_points_and_margin() {
+        let svg = write_svg(&contour_layers(&contours(), false), &options());
+        assert!(svg.contains(r#"viewBox="0 0 120.00 70.00" width="120" height="70""#));
+        // y retourné : le coin (0, 0) du monde est en bas à gauche
+        assert!(svg.contains("M10.00,60.00 L110.00,60.00 L110.00,10.00 L10.00,10.00 Z"));
+    }
+
+    #[test]
+    fn one_layer_per_band() {
+        let layers = contour_layers(&contours(), true);
+        let names: Vec<_> = layers.iter().map(|layer| layer.name.as_str()).collect();
+        assert_eq!(names, ["coastline", "depth_-1", "depth_-2", "elevation_1"]);
+        assert_eq!(layers[1].paths.len(), 2);
+
+        let svg = write_svg(&layers, &options());
+        assert_eq!(svg.matches("<g ").count(), 4);
+        assert_eq!(svg.matches("<path ").count(), 5);
+        // Seule la côte est remplie, un chemin ouvert ne l'est jamais
+        assert_eq!(svg.matches(r#"fill="none""#).count(), 4);
+        assert!(svg.contains(r#"id="depth_-1""#));
+        assert!(svg.contains(r#"<path d="M19.00,49.00 L29.00,49.00" fill="none"/>"#));
+    }
+
+    #[test]
+    fn smoothed_and_raw_paths_differ() {
+        let contours = contours();
+        let raw = write_svg(&contour_layers(&contours, false), &options());
+        let smoothed = write_svg(&contour_layers(&contours, true), &options());
+        assert_ne!(raw, smoothed);
+        assert!(raw.contains("M10.00,60.00"));
+        assert!(smoothed.contains("M10.00,58.00"));
+    }
+}
@@ -0,0 +1,62 @@
+use bevy::prelude::*;
+
+use super::super::TerrainContours;
+use super::super::terrain::TerrainMeshes;
+use crate::input::{ActionInput, InputAction};
+
+use super::{MeshExportOptions, SvgExportOptions, contour_layers, export_meshes, export_svg};
+
+/// F6 (par défaut) : exporte les contours générés en SVG
+pub fn export_svg_on_key(
+    input: ActionInput,
+    contours: Option<Res<TerrainContours>>,
+    options: Res<SvgExportOptions>,
+) {
+    if !input.just_pressed(InputAction::ExportSvg) {
+        return;
+    }
+
+    let Some(contours) = contours else {
+        warn!("Aucun contour à exporter");
+        return;
+    };
+
+    let layers = contour_layers(&contours, options.smoothed);
+    match export_svg(&options.output_path, &layers, &options) {
+        Ok(()) => info!("✓ Contours exportés: {}", options.output_path.display()),
+        Err(err) => error!(
+            "Échec de l'export SVG ({}): {}",
+            options.output_path.display(),
+            err
+        ),
+    }
+}
+
+/// F7 (par défaut) : exporte les meshes de terrain en glTF / OBJ
+pub fn export_meshes_on_key(
+    input: ActionInput,
+    meshes: Option<Res<TerrainMeshes>>,
+    options: Res<MeshExportOptions>,
+) {
+    if !input.just_pressed(InputAction::ExportMeshes) {
+        return;
+    }
+
+    let Some(meshes) = meshes else {
+        warn!("Aucun mesh de terrain à exporter");
+        return;
+    };
+
+    match export_meshes(&options.output_path, &meshes, &options.formats) {
+        Ok(paths) => {
+            for path in paths {
+                info!("✓ Mesh exporté: {}", path.display());
+            }
+        }
+        Err(err) => error!(
+            "Échec de l'export des meshes ({}): {}",
+            options.output_path.display(),
+            err
+        ),
+    }
+}
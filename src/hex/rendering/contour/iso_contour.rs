@@ -0,0 +1,21 @@
+use serde::{Deserialize, Serialize};
+
+use super::ContourPath;
+
+/// Famille d'une ligne d'iso-valeur
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum IsoContourKind {
+    // Isobathe (profondeur, en mer)
+    Depth,
+    // Courbe de niveau (élévation, sur terre)
+    Elevation,
+}
+
+/// Contour extrait à un niveau donné du champ de distance signé
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct IsoContour {
+    // Niveau en hexagones depuis la côte (négatif en mer)
+    pub level: f32,
+    pub kind: IsoContourKind,
+    pub path: ContourPath,
+}
@@ -1,9 +1,20 @@
+mod coastline_noise;
 mod contour_config;
+mod contour_lod;
 mod contour_path;
+mod contour_smoothing;
+pub mod export;
+mod iso_contour;
 mod plugins;
 pub mod systems;
 pub mod terrain;
+mod terrain_contours;
 
+pub use coastline_noise::CoastlineNoise;
 pub use contour_config::ContourConfig;
+pub use contour_lod::{ContourLod, ContourLodLevel, update_contour_lod};
 pub use contour_path::ContourPath;
+pub use contour_smoothing::ContourSmoothing;
+pub use iso_contour::{IsoContour, IsoContourKind};
 pub use plugins::{OptimizedTerrainPlugin, OrganicContourPlugin};
+pub use terrain_contours::TerrainContours;
@@ -1,14 +1,35 @@
 use bevy::prelude::*;
 
 use super::super::systems;
+use super::super::{export, update_contour_lod};
+use crate::hex::rendering::systems::setup_hex_config;
+use crate::state::components::MapSource;
 
 pub struct OrganicContourPlugin;
 
 impl Plugin for OrganicContourPlugin {
     fn build(&self, app: &mut App) {
-        app.add_systems(
+        app.init_resource::<MapSource>()
+            .add_systems(
             Startup,
-            (systems::setup_organic_contour).chain(),
+            // La HexConfig est insérée par HexRenderingPlugin
+            systems::setup_organic_contour.after(setup_hex_config),
+        )
+        .init_resource::<export::SvgExportOptions>()
+        .init_resource::<export::MeshExportOptions>()
+        .add_systems(
+            Update,
+            (
+                update_contour_lod,
+                export::systems::export_svg_on_key,
+                export::systems::export_meshes_on_key,
+                (
+                    systems::schedule_terrain_regeneration,
+                    systems::start_terrain_regeneration,
+                    systems::finish_terrain_regeneration,
+                )
+                    .chain(),
+            ),
         );
     }
 }
@@ -1,8 +1,16 @@
 mod organic_contour_system;
 mod process_terrain_generation_system;
 mod terrain_generation_system;
-mod utilities;
+mod terrain_regeneration_system;
+pub mod utilities;
 
-pub use organic_contour_system::setup_organic_contour;
+pub use organic_contour_system::{
+    LiveTerrain, MarchingGrid, OrganicContourSystem, load_or_generate_terrain, map_bounds,
+    organic_contour_config, setup_organic_contour,
+};
 pub use process_terrain_generation_system::process_terrain_generation;
-pub use terrain_generation_system::start_terrain_generation;
\ No newline at end of file
+pub use terrain_generation_system::start_terrain_generation;
+pub use terrain_regeneration_system::{
+    TerrainRegeneration, finish_terrain_regeneration, schedule_terrain_regeneration,
+    start_terrain_regeneration,
+};
\ No newline at end of file
@@ -1,21 +1,29 @@
-use bevy::asset::RenderAssetUsages;
-
-use bevy::mesh::Indices;
 use bevy::prelude::*;
-use bevy::render::render_resource::PrimitiveTopology;
 
 use hexx::*;
-use image::{DynamicImage, GenericImageView, Rgba};
-
-use noise::{NoiseFn, Perlin};
-use std::collections::HashMap;
-
+use image::{DynamicImage, GenericImageView};
+use rayon::prelude::*;
+
+use std::collections::hash_map::DefaultHasher;
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::hash::{Hash, Hasher};
+use std::io;
+use std::path::Path;
+use std::sync::Mutex;
+
+use super::super::terrain::{TerrainCells, TerrainMesh, TerrainMeshData, TerrainMeshes};
+use super::terrain_regeneration_system::TerrainRegeneration;
+use super::utilities;
 use crate::{
     hex::{
-        HexConfig, HexCoord,
-        rendering::contour::{ContourConfig, ContourPath},
+        HexConfig, HexCoord, HexMap, HexRegions, RegionKind,
+        rendering::contour::{
+            CoastlineNoise, ContourConfig, ContourLod, ContourPath, ContourSmoothing,
+            IsoContour, IsoContourKind, TerrainContours,
+        },
     },
     shared::types::{CellData, TerrainType, Triangle, TriangleId},
+    state::{cache::TerrainCache, components::MapSource},
 };
 
 pub fn setup_organic_contour(
@@ -23,48 +31,461 @@ pub fn setup_organic_contour(
     mut meshes: ResMut<Assets<Mesh>>,
     mut materials: ResMut<Assets<ColorMaterial>>,
     hex_config: Res<HexConfig>,
+    map_source: Res<MapSource>,
     // images: Res<Assets<Image>>,
     // asset_server: Res<AssetServer>,
 ) {
     // let binary_map_handle = asset_server.load("maps/Gaulyia_binarymap.png");
 
-    let image_path = "assets/maps/Gaulyia_binarymap.png";
-    let binary_map = image::open(image_path).expect("Failed to load binary map image");
-    // Charger votre binary map
-    // let binary_map = images.get(&binary_map).unwrap();
-
-    // Configuration
-    let config = ContourConfig {
-        pixels_per_hex: 0.25, // 4 hex par pixel
-        noise_amplitude: 0.2, // Force du bruit
-        noise_frequency: 3.0, // Fréquence du bruit
-        noise_octaves: 8,     // Détail fractal
-        threshold: 0.5,       // Seuil terre/mer
-        spline_tension: 0.5,  // Courbure des splines
+    let config = organic_contour_config();
+    // La régénération reste possible après un échec (carte d'une sauvegarde)
+    commands.insert_resource(TerrainRegeneration::new(
+        hex_config.clone(),
+        map_source.clone(),
+        config.clone(),
+    ));
+    let terrain = match load_or_generate_terrain(&hex_config, &map_source, &config) {
+        Ok(terrain) => terrain,
+        Err(err) => {
+            error!("Échec de la génération du terrain ({}) : {}", map_source.image_path, err);
+            return;
+        }
+    };
+
+    info!("Generated {} contours", terrain.contours.coastline.len());
+    info!("Generated {} iso-contours", terrain.contours.iso_contours.len());
+
+    spawn_terrain(&mut commands, &mut meshes, &mut materials, terrain, &config);
+}
+
+/// Terrain de la carte d'origine, repris du cache disque quand les entrées
+/// n'ont pas changé. Une carte éditée passe par [`LiveTerrain`].
+/// Échoue si l'image de la source (éventuellement venue d'une sauvegarde)
+/// est absente ou illisible.
+pub fn load_or_generate_terrain(
+    hex_config: &HexConfig,
+    map_source: &MapSource,
+    config: &ContourConfig,
+) -> io::Result<TerrainCache> {
+    let image_bytes = std::fs::read(&map_source.image_path)?;
+    let key = TerrainCache::key(&image_bytes, hex_config, config, map_source.hex_radius);
+    let cache_path = Path::new(TERRAIN_CACHE_PATH);
+
+    // Réutiliser le cache si les entrées n'ont pas changé
+    if let Some(cache) = TerrainCache::load(cache_path, key) {
+        match cache.meshes.validate() {
+            Ok(()) => {
+                info!("✓ Terrain chargé depuis le cache ({})", cache_path.display());
+                return Ok(cache);
+            }
+            Err((name, err)) => warn!("Cache du terrain ignoré, mesh {} invalide : {}", name, err),
+        }
+    }
+
+    let system = source_contour_system(&image_bytes, hex_config, map_source, config)?;
+
+    // Générer contours, iso-contours et meshes
+    let (contours, meshes) = system.generate();
+
+    let cache = TerrainCache {
+        key,
+        cells: system.hex_cells().clone(),
+        contours,
+        meshes,
     };
 
+    match cache.meshes.validate() {
+        Ok(()) => match cache.save(cache_path) {
+            Ok(()) => info!("✓ Terrain mis en cache ({})", cache_path.display()),
+            Err(err) => warn!("Impossible d'écrire le cache du terrain: {}", err),
+        },
+        Err((name, err)) => error!("Mesh de terrain {} invalide : {}", name, err),
+    }
+    Ok(cache)
+}
+
+// Système de contours de la source, grille initialisée
+fn source_contour_system(
+    image_bytes: &[u8],
+    hex_config: &HexConfig,
+    map_source: &MapSource,
+    config: &ContourConfig,
+) -> io::Result<OrganicContourSystem> {
+    let binary_map = image::load_from_memory(image_bytes)
+        .map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))?;
+
     // Créer le système
     let layout = hex_config.layout.clone();
-    let mut system = OrganicContourSystem::new(binary_map, layout, config);
+    let mut system = OrganicContourSystem::new(binary_map, layout, config.clone());
+    system.initialize_hex_grid(map_source.hex_radius);
+    Ok(system)
+}
+
+/// Terrain résident de l'édition. La binary map décodée, les cellules et les
+/// segments du marching squares restent en mémoire entre deux coups de
+/// pinceau : seuls les segments autour des cellules modifiées sont recalculés,
+/// et les contours inchangés gardent leur triangulation.
+pub struct LiveTerrain {
+    key: u64,
+    system: OrganicContourSystem,
+    overrides: HashMap<HexCoord, TerrainType>,
+    coastline_segments: CellSegments,
+    iso_segments: Vec<CellSegments>,
+}
+
+impl LiveTerrain {
+    /// Lit la source et génère le terrain complet avec les cellules modifiées
+    pub fn load(
+        hex_config: &HexConfig,
+        map_source: &MapSource,
+        config: &ContourConfig,
+        overrides: &HashMap<HexCoord, TerrainType>,
+    ) -> io::Result<(Self, TerrainCache)> {
+        let image_bytes = std::fs::read(&map_source.image_path)?;
+        let key = TerrainCache::key(&image_bytes, hex_config, config, map_source.hex_radius);
+        let system = source_contour_system(&image_bytes, hex_config, map_source, config)?;
+        Ok(Self::new(key, system, overrides))
+    }
+
+    // Segments complets du système, cellules modifiées appliquées
+    fn new(
+        key: u64,
+        mut system: OrganicContourSystem,
+        overrides: &HashMap<HexCoord, TerrainType>,
+    ) -> (Self, TerrainCache) {
+        system
+            .triangulations
+            .get_mut()
+            .expect("Mémo des triangulations empoisonné")
+            .enabled = true;
+        system.apply_overrides(overrides);
+
+        let coastline_grid = system.marching_grid();
+        let iso_grid = system.iso_grid();
+        let mut live = Self {
+            key,
+            coastline_segments: system
+                .coastline_segments(&coastline_grid, CellWindow::full(&coastline_grid)),
+            iso_segments: system.iso_segments(&iso_grid, CellWindow::full(&iso_grid)),
+            system,
+            overrides: overrides.clone(),
+        };
+        let terrain = live.terrain();
+        (live, terrain)
+    }
+
+    /// Remplace les cellules modifiées et régénère le terrain autour des
+    /// cellules dont la valeur a changé
+    pub fn update(&mut self, overrides: &HashMap<HexCoord, TerrainType>) -> TerrainCache {
+        // Le champ de la côte ne change que sur les hexagones repeints
+        let repainted: Vec<HexCoord> = self
+            .overrides
+            .keys()
+            .chain(overrides.keys())
+            .filter(|coord| self.overrides.get(coord) != overrides.get(coord))
+            .copied()
+            .collect();
+        if repainted.is_empty() {
+            return self.terrain();
+        }
+
+        let previous = self.system.hex_cells.clone();
+        self.system.apply_overrides(overrides);
+        self.overrides = overrides.clone();
+
+        let coastline_grid = self.system.marching_grid();
+        let (min, max) = map_bounds(&self.system.hex_layout, &repainted);
+        if let Some(window) = coastline_grid.window_around(min, max) {
+            let fresh = self.system.coastline_segments(&coastline_grid, window);
+            window.replace(&coastline_grid, &mut self.coastline_segments, fresh);
+        }
+
+        // Le champ de distance signé d'un point dépend de son hexagone et des
+        // voisins : toute cellule dont la distance a changé compte
+        let threshold = self.system.config.threshold;
+        let cells = self.system.hex_cells();
+        let moved: HashSet<HexCoord> = previous
+            .iter()
+            .filter(|(coord, before)| {
+                let after = &cells[*coord];
+                (before.sample_value > threshold) != (after.sample_value > threshold)
+                    || before.distance_to_edge != after.distance_to_edge
+            })
+            .flat_map(|(coord, _)| std::iter::once(coord).chain(coord.neighbors()))
+            .collect();
+        if !moved.is_empty() {
+            let iso_grid = self.system.iso_grid();
+            let (min, max) = map_bounds(&self.system.hex_layout, &moved);
+            if let Some(window) = iso_grid.window_around(min, max) {
+                let fresh = self.system.iso_segments(&iso_grid, window);
+                for (segments, fresh) in self.iso_segments.iter_mut().zip(fresh) {
+                    window.replace(&iso_grid, segments, fresh);
+                }
+            }
+        }
+
+        info!(
+            "Terrain régénéré autour de {} cellules repeintes",
+            repainted.len()
+        );
+        self.terrain()
+    }
+
+    // Contours et meshes à partir des segments courants
+    fn terrain(&mut self) -> TerrainCache {
+        let raw_coastline = self.system.connect_cell_segments(&self.coastline_segments);
+        let raw_iso_contours = self.system.connect_iso_segments(&self.iso_segments);
+        let (contours, meshes) = self.system.build_terrain(raw_coastline, raw_iso_contours);
+        self.system
+            .triangulations
+            .get_mut()
+            .expect("Mémo des triangulations empoisonné")
+            .rotate();
+
+        TerrainCache {
+            key: self.key,
+            cells: self.system.hex_cells().clone(),
+            contours,
+            meshes,
+        }
+    }
+}
+
+// Spawne les meshes du terrain généré et conserve les données pour les exports.
+// `config` est celle qui a produit les cellules (seuil terre/mer des régions).
+pub(super) fn spawn_terrain(
+    commands: &mut Commands,
+    meshes: &mut Assets<Mesh>,
+    materials: &mut Assets<ColorMaterial>,
+    terrain: TerrainCache,
+    config: &ContourConfig,
+) {
+    let TerrainCache {
+        cells,
+        contours: terrain_contours,
+        meshes: terrain_meshes,
+        ..
+    } = terrain;
+
+    // Les meshes invalides ne sont pas envoyés au GPU
+    let valid = |name: &str, data: &TerrainMeshData| match data.validate() {
+        Ok(()) => true,
+        Err(err) => {
+            error!("Mesh {} ignoré : {}", name, err);
+            false
+        }
+    };
+
+    // Un mesh par niveau de détail
+    let lod_levels: Vec<_> = terrain_meshes
+        .lod_levels
+        .iter()
+        .filter(|(_, data)| valid("LOD", data))
+        .cloned()
+        .collect();
+    let lod = ContourLod::from_mesh_data(&lod_levels, meshes);
+    if let Some(full_detail) = lod.levels.first().map(|level| level.mesh.clone()) {
+        commands.spawn((
+            Name::new("Contour"),
+            Mesh2d(full_detail),
+            MeshMaterial2d(materials.add(ColorMaterial::from_color(LAND_COLOR))),
+            lod,
+            TerrainMesh,
+        ));
+    }
 
-    // Initialiser la grille
-    system.initialize_hex_grid(100);
+    // Isobathes et courbes de niveau, rendues en lignes fines au-dessus du terrain
+    for (name, data, color) in [
+        (
+            "Isobathes",
+            &terrain_meshes.depth_lines,
+            Color::srgba(0.15, 0.3, 0.6, 0.6),
+        ),
+        (
+            "Courbes de niveau",
+            &terrain_meshes.elevation_lines,
+            Color::srgba(0.45, 0.3, 0.15, 0.6),
+        ),
+    ] {
+        if data.vertices.is_empty() || !valid(name, data) {
+            continue;
+        }
+
+        commands.spawn((
+            Name::new(name),
+            Mesh2d(meshes.add(data.clone().into_mesh())),
+            MeshMaterial2d(materials.add(ColorMaterial::from_color(color))),
+            Transform::from_xyz(0.0, 0.0, 0.1),
+            TerrainMesh,
+        ));
+    }
 
-    // Générer les contours
-    let contours = system.generate_organic_contours_global();
-    // let contours = system.generate_organic_contours();
-    info!("Generated {} contours", contours.len());
+    // Lacs et mers intérieures, trous de la terre
+    if !terrain_meshes.lakes.vertices.is_empty() && valid("Lacs", &terrain_meshes.lakes) {
+        commands.spawn((
+            Name::new("Lacs"),
+            Mesh2d(meshes.add(terrain_meshes.lakes.clone().into_mesh())),
+            MeshMaterial2d(materials.add(ColorMaterial::default())),
+            Transform::from_xyz(0.0, 0.0, 0.02),
+            TerrainMesh,
+        ));
+    }
 
-    // Créer le mesh
-    let mesh = system.generate_mesh(&contours);
-    info!("Mesh vertices count: {}", mesh.count_vertices());
+    // Bandes d'eau (isobathes remplies) sous la terre, et trait de côte au-dessus
+    for (name, data, z) in [
+        ("Bandes d'eau", &terrain_meshes.water_bands, -0.5),
+        ("Trait de côte", &terrain_meshes.outline, 0.05),
+    ] {
+        if !valid(name, data) {
+            continue;
+        }
+        commands.spawn((
+            Name::new(name),
+            Mesh2d(meshes.add(data.clone().into_mesh())),
+            MeshMaterial2d(materials.add(ColorMaterial::default())),
+            Transform::from_xyz(0.0, 0.0, z),
+            TerrainMesh,
+        ));
+    }
 
-    // Spawner l'entité
-    commands.spawn((
-        Name::new("Contour"),
-        Mesh2d(meshes.add(mesh)),
-        MeshMaterial2d(materials.add(ColorMaterial::from_color(Color::srgb(0.8, 0.6, 0.4)))),
+    // Conserver cellules, régions, contours et meshes (édition, gameplay, exports)
+    let threshold = config.threshold;
+    let terrain_cells = TerrainCells::from_cells(cells);
+    commands.insert_resource(HexRegions::compute(
+        &terrain_cells
+            .cells
+            .par_map(|_, cell| cell.sample_value > threshold),
     ));
+    commands.insert_resource(terrain_cells);
+    commands.insert_resource(terrain_meshes);
+    commands.insert_resource(terrain_contours);
+}
+
+const TERRAIN_CACHE_PATH: &str = "cache/terrain.bin";
+
+// Distance signée hors de la grille : haute mer, sous toutes les isobathes
+const OPEN_SEA_DISTANCE: f32 = -1.0e6;
+
+/// Configuration des contours utilisée pour la carte Gaulyia
+pub fn organic_contour_config() -> ContourConfig {
+    ContourConfig {
+        pixels_per_hex: 0.25,  // 4 hex par pixel
+        seed: 42,              // Graine du monde
+        noise_amplitude: 0.2,  // Force du bruit
+        noise_frequency: 0.2,  // Fréquence du bruit (cycles par hexagone)
+        noise_octaves: 8,      // Détail fractal
+        warp_amplitude: 6.0,   // Déformation du domaine (fjords)
+        threshold: 0.5,        // Seuil terre/mer
+        spline_tension: 0.5,   // Courbure des splines
+        smoothing: ContourSmoothing::CentripetalCatmullRom { segments: 10 },
+        ..default()
+    }
+}
+
+const LAND_COLOR: Color = Color::srgb(0.8, 0.6, 0.4);
+const OUTLINE_COLOR: Color = Color::srgb(0.35, 0.27, 0.19);
+const DEEP_WATER_COLOR: Color = Color::srgb(0.1, 0.25, 0.55);
+const SHALLOW_WATER_COLOR: Color = Color::srgb(0.35, 0.6, 0.8);
+const LAKE_COLOR: Color = Color::srgb(0.3, 0.55, 0.7);
+
+/// Limites monde de la carte : hexagones des coordonnées, marge d'un hexagone
+pub fn map_bounds<'a>(
+    layout: &HexLayout,
+    coords: impl IntoIterator<Item = &'a HexCoord>,
+) -> (Vec2, Vec2) {
+    let mut min = Vec2::new(f32::MAX, f32::MAX);
+    let mut max = Vec2::new(f32::MIN, f32::MIN);
+
+    for coord in coords {
+        let pos = layout.hex_to_world_pos(coord.to_hex());
+        let size = layout.scale;
+        min = min.min(pos - size);
+        max = max.max(pos + size);
+    }
+
+    (min, max)
+}
+
+/// Grille régulière du marching squares qui extrait le trait de côte
+#[derive(Clone, Copy, Debug)]
+pub struct MarchingGrid {
+    pub min: Vec2,
+    pub max: Vec2,
+    // Nombre de cellules par côté
+    pub resolution: usize,
+}
+
+impl MarchingGrid {
+    pub const RESOLUTION: usize = 2000;
+
+    pub fn new<'a>(layout: &HexLayout, coords: impl IntoIterator<Item = &'a HexCoord>) -> Self {
+        let (min, max) = map_bounds(layout, coords);
+        Self {
+            min,
+            max,
+            resolution: Self::RESOLUTION,
+        }
+    }
+
+    pub fn cell_size(&self) -> Vec2 {
+        (self.max - self.min) / self.resolution as f32
+    }
+
+    // Indice d'une cellule, ligne par ligne
+    fn cell_index(&self, x: usize, y: usize) -> usize {
+        y * self.resolution + x
+    }
+
+    // Cellules dont un coin tombe dans le rectangle monde, `None` hors de la grille
+    fn window_around(&self, min: Vec2, max: Vec2) -> Option<CellWindow> {
+        let cell_size = self.cell_size().max(Vec2::splat(f32::EPSILON));
+        let first = ((min - self.min) / cell_size).floor() - Vec2::ONE;
+        let last = ((max - self.min) / cell_size).ceil() + Vec2::ONE;
+        let limit = self.resolution as f32;
+        if last.x < 0.0 || last.y < 0.0 || first.x > limit || first.y > limit {
+            return None;
+        }
+
+        let clamp = |value: f32| value.clamp(0.0, limit) as usize;
+        let window = CellWindow {
+            min: (clamp(first.x), clamp(first.y)),
+            max: (clamp(last.x), clamp(last.y)),
+        };
+        (window.min.0 < window.max.0 && window.min.1 < window.max.1).then_some(window)
+    }
+}
+
+// Segments du marching squares étiquetés par l'indice de leur cellule, dans
+// l'ordre des cellules : une fenêtre peut être remplacée sans tout recalculer
+type CellSegments = Vec<(usize, (Vec2, Vec2))>;
+
+// Cellules [min, max[ d'une grille du marching squares
+#[derive(Clone, Copy, Debug)]
+struct CellWindow {
+    min: (usize, usize),
+    max: (usize, usize),
+}
+
+impl CellWindow {
+    fn full(grid: &MarchingGrid) -> Self {
+        Self {
+            min: (0, 0),
+            max: (grid.resolution, grid.resolution),
+        }
+    }
+
+    fn contains(&self, grid: &MarchingGrid, cell: usize) -> bool {
+        let (x, y) = (cell % grid.resolution, cell / grid.resolution);
+        (self.min.0..self.max.0).contains(&x) && (self.min.1..self.max.1).contains(&y)
+    }
+
+    // Remplace les segments de la fenêtre en gardant l'ordre des cellules
+    fn replace(&self, grid: &MarchingGrid, segments: &mut CellSegments, fresh: CellSegments) {
+        segments.retain(|(cell, _)| !self.contains(grid, *cell));
+        segments.extend(fresh);
+        // Tri stable : les segments d'une même cellule gardent leur ordre
+        segments.sort_by_key(|(cell, _)| *cell);
+    }
 }
 
 // Système principal de génération des contours
@@ -72,19 +493,221 @@ pub struct OrganicContourSystem {
     binary_map: DynamicImage,
     hex_layout: HexLayout,
     config: ContourConfig,
-    hex_cells: HashMap<Hex, CellData>,
-    perlin: Perlin,
+    hex_cells: HexMap<CellData>,
+    // Valeurs imposées par l'édition (1.0 = terre, 0.0 = mer)
+    overrides: HashMap<Hex, f32>,
+    noise: CoastlineNoise,
+    // Cellules par côté de la grille du trait de côte
+    marching_resolution: usize,
+    triangulations: Mutex<TriangulationMemo>,
+}
+
+// Triangulations des contours, conservées d'une génération à la suivante
+// pour le terrain résident de l'édition
+#[derive(Default)]
+struct TriangulationMemo {
+    enabled: bool,
+    // Génération précédente, puis génération en cours
+    previous: HashMap<u64, TerrainMeshData>,
+    current: HashMap<u64, TerrainMeshData>,
+}
+
+impl TriangulationMemo {
+    fn key(points: &[Vec2]) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        for point in points {
+            point.x.to_bits().hash(&mut hasher);
+            point.y.to_bits().hash(&mut hasher);
+        }
+        hasher.finish()
+    }
+
+    // Oublie les contours absents de la dernière génération
+    fn rotate(&mut self) {
+        self.previous = std::mem::take(&mut self.current);
+    }
 }
 
 impl OrganicContourSystem {
     pub fn new(binary_map: DynamicImage, hex_layout: HexLayout, config: ContourConfig) -> Self {
+        let noise = CoastlineNoise::new(&config, hex_layout.scale);
         Self {
             binary_map,
             hex_layout,
             config,
-            hex_cells: HashMap::new(),
-            perlin: Perlin::new(42),
+            hex_cells: HexMap::default(),
+            overrides: HashMap::new(),
+            noise,
+            marching_resolution: MarchingGrid::RESOLUTION,
+            triangulations: Mutex::default(),
+        }
+    }
+
+    /// Grille du trait de côte plus grossière, pour les aperçus et les tests
+    pub fn with_marching_resolution(mut self, resolution: usize) -> Self {
+        self.marching_resolution = resolution.max(1);
+        self
+    }
+
+    pub fn config(&self) -> &ContourConfig {
+        &self.config
+    }
+
+    pub fn hex_cells(&self) -> &HexMap<CellData> {
+        &self.hex_cells
+    }
+
+    // Pipeline complet sans rendu : contours bruts et lissés, iso-contours,
+    // puis meshes de terre, bandes d'eau et trait de côte
+    pub fn generate(&self) -> (TerrainContours, TerrainMeshes) {
+        self.build_terrain(self.extract_contours_global(), self.extract_iso_contours())
+    }
+
+    // Lissage, classement des côtes et meshes à partir des contours bruts
+    fn build_terrain(
+        &self,
+        raw_coastline: Vec<ContourPath>,
+        raw_iso_contours: Vec<IsoContour>,
+    ) -> (TerrainContours, TerrainMeshes) {
+        let coastline: Vec<ContourPath> = raw_coastline
+            .iter()
+            .cloned()
+            .map(|c| self.smooth_contour_with_splines(c))
+            .collect();
+
+        let iso_contours: Vec<IsoContour> = raw_iso_contours
+            .iter()
+            .cloned()
+            .map(|iso| self.smooth_iso_contour(iso))
+            .collect();
+
+        // Les côtes qui entourent de l'eau sont des trous de la terre : lacs,
+        // ou mer intérieure si l'eau rejoint l'océan hors du contour
+        let regions = self.regions();
+        let mut outer = Vec::new();
+        let mut lakes = Vec::new();
+        let mut inland_seas = Vec::new();
+        for (raw, smoothed) in raw_coastline.iter().zip(&coastline) {
+            match self.enclosed_water(raw, &regions) {
+                None => outer.push(smoothed.clone()),
+                Some(RegionKind::Lake) => lakes.push(smoothed.clone()),
+                Some(_) => inland_seas.push(smoothed.clone()),
+            }
+        }
+
+        let iso_lines = |kind: IsoContourKind| {
+            let paths: Vec<ContourPath> = iso_contours
+                .iter()
+                .filter(|iso| iso.kind == kind)
+                .map(|iso| iso.path.clone())
+                .collect();
+            self.build_line_mesh_data(&paths, self.config.iso_line_width)
+        };
+
+        let meshes = TerrainMeshes {
+            land: self.build_mesh_data(&outer).with_color(LAND_COLOR),
+            lakes: utilities::merge_mesh_data(vec![
+                self.build_mesh_data(&lakes).with_color(LAKE_COLOR),
+                self.build_mesh_data(&inland_seas).with_color(SHALLOW_WATER_COLOR),
+            ]),
+            water_bands: self.build_water_bands(&iso_contours),
+            outline: self
+                .build_line_mesh_data(&coastline, self.config.iso_line_width)
+                .with_color(OUTLINE_COLOR),
+            lod_levels: self.build_lod_mesh_data(&outer),
+            depth_lines: iso_lines(IsoContourKind::Depth),
+            elevation_lines: iso_lines(IsoContourKind::Elevation),
+        };
+
+        let contours = TerrainContours {
+            raw_coastline,
+            coastline,
+            raw_iso_contours,
+            iso_contours,
+        };
+
+        (contours, meshes)
+    }
+
+    /// Îles, lacs et océans de la grille
+    pub fn regions(&self) -> HexRegions {
+        HexRegions::compute(
+            &self
+                .hex_cells
+                .par_map(|_, cell| cell.sample_value > self.config.threshold),
+        )
+    }
+
+    // Type de l'eau entourée par une côte fermée, `None` si l'intérieur est
+    // de la terre. L'intérieur est sondé juste à côté de plusieurs arêtes.
+    fn enclosed_water(&self, raw: &ContourPath, regions: &HexRegions) -> Option<RegionKind> {
+        let points = &raw.points;
+        if !raw.is_closed || points.len() < 3 {
+            return None;
+        }
+
+        let signed_area: f32 = points
+            .iter()
+            .zip(points.iter().cycle().skip(1))
+            .map(|(a, b)| a.perp_dot(*b))
+            .sum();
+        let offset = self.hex_layout.scale.min_element() * 0.1;
+        let step = (points.len() / 8).max(1);
+
+        let mut votes = 0;
+        let mut water_probes = Vec::new();
+        for i in (0..points.len()).step_by(step) {
+            let a = points[i];
+            let b = points[(i + 1) % points.len()];
+            let Some(left) = (b - a).perp().try_normalize() else {
+                continue;
+            };
+            // L'intérieur est à gauche d'un contour CCW
+            let inward = if signed_area > 0.0 { left } else { -left };
+            let probe = (a + b) * 0.5 + inward * offset;
+
+            votes += 1;
+            if self.coastline_field(probe) <= self.config.threshold {
+                water_probes.push(probe);
+            }
         }
+
+        if water_probes.len() * 2 <= votes {
+            return None;
+        }
+
+        // Sans cellule d'eau étiquetée sous les sondes, l'eau est considérée comme un lac
+        let kind = water_probes
+            .iter()
+            .filter_map(|probe| {
+                let hex = self.hex_layout.world_pos_to_hex(*probe);
+                regions.region_of(&HexCoord::from_hex(hex))
+            })
+            .find(|region| region.is_water())
+            .map_or(RegionKind::Lake, |region| region.kind);
+        Some(kind)
+    }
+
+    // Valeur du champ terre/mer dont la côte est l'iso-ligne au seuil :
+    // binary map (ou cellule peinte) plus bruit fractal
+    fn coastline_field(&self, world_pos: Vec2) -> f32 {
+        let img_x =
+            world_pos.x * self.config.pixels_per_hex + self.binary_map.width() as f32 / 2.0;
+        let img_y =
+            world_pos.y * self.config.pixels_per_hex + self.binary_map.height() as f32 / 2.0;
+
+        let base_value = if self.overrides.is_empty() {
+            self.sample_at_position(img_x, img_y)
+        } else {
+            let hex = self.hex_layout.world_pos_to_hex(world_pos);
+            match self.overrides.get(&hex) {
+                Some(value) => *value,
+                None => self.sample_at_position(img_x, img_y),
+            }
+        };
+        let noise = self.fractal_noise(world_pos.x, world_pos.y) * self.config.noise_amplitude;
+
+        base_value + noise
     }
 
     // Échantillonne la binary map pour un hexagone donné
@@ -114,7 +737,7 @@ impl OrganicContourSystem {
         let mut water_neighbors = 0;
 
         for neighbor in hex.all_neighbors() {
-            let neighbor_sample = self.sample_binary_map(neighbor);
+            let neighbor_sample = self.sample_hex(neighbor);
             if neighbor_sample > self.config.threshold {
                 land_neighbors += 1;
             } else {
@@ -144,23 +767,85 @@ impl OrganicContourSystem {
         (terrain_type, is_border)
     }
 
+    // Valeur d'un hexagone, modifications de l'édition comprises
+    fn sample_hex(&self, hex: Hex) -> f32 {
+        self.overrides
+            .get(&hex)
+            .copied()
+            .unwrap_or_else(|| self.sample_binary_map(hex))
+    }
+
+    // Valeur d'échantillon d'un terrain peint. La plage est la frontière côté
+    // mer (`is_land` faux) : sous le seuil, la côte passe entre elle et la
+    // terre voisine, comme pour une plage détectée sur la carte.
+    fn painted_sample(terrain_type: TerrainType) -> f32 {
+        match terrain_type {
+            TerrainType::DeepWater | TerrainType::ShallowWater | TerrainType::Beach => 0.0,
+            TerrainType::Cliff | TerrainType::Land => 1.0,
+        }
+    }
+
+    /// Impose le terrain peint sur des cellules, en remplaçant les modifications
+    /// précédentes. Les cellules voisines sont reclassées et le champ de
+    /// distance recalculé.
+    pub fn apply_overrides(&mut self, overrides: &HashMap<HexCoord, TerrainType>) {
+        let values: HashMap<Hex, f32> = overrides
+            .iter()
+            .map(|(coord, terrain_type)| (coord.to_hex(), Self::painted_sample(*terrain_type)))
+            .collect();
+        if values.is_empty() && self.overrides.is_empty() {
+            return;
+        }
+
+        // Les cellules qui ne sont plus peintes retrouvent leur classification
+        let touched: HashSet<Hex> = self
+            .overrides
+            .keys()
+            .chain(values.keys())
+            .flat_map(|hex| std::iter::once(*hex).chain(hex.all_neighbors()))
+            .collect();
+        self.overrides = values;
+
+        for hex in touched {
+            let coord = HexCoord::from_hex(hex);
+            if !self.hex_cells.contains(&coord) {
+                continue;
+            }
+            let sample_value = self.sample_hex(hex);
+            let (terrain_type, is_border) = self.determine_terrain_type(hex, sample_value);
+            let cell = &mut self.hex_cells[coord];
+            cell.sample_value = sample_value;
+            cell.terrain_type = terrain_type;
+            cell.is_border = is_border;
+        }
+
+        // Le type peint l'emporte sur la classification automatique
+        for (coord, terrain_type) in overrides {
+            if let Some(cell) = self.hex_cells.get_mut(coord) {
+                cell.terrain_type = *terrain_type;
+            }
+        }
+
+        self.calculate_distance_fields();
+    }
+
     // Initialise les cellules hexagonales
     pub fn initialize_hex_grid(&mut self, radius: u32) {
-        for hex in Hex::ZERO.range(radius) {
+        // Chaque cellule ne dépend que de la binary map : échantillonnage parallèle
+        let grid = HexMap::hexagon(HexCoord::new(0, 0), radius, |_| ());
+        self.hex_cells = grid.par_map(|coord, _| {
+            let hex = coord.to_hex();
             let sample_value = self.sample_binary_map(hex);
             let (terrain_type, is_border) = self.determine_terrain_type(hex, sample_value);
 
-            self.hex_cells.insert(
-                hex,
-                CellData {
-                    coord: HexCoord::from_hex(hex),
-                    terrain_type,
-                    is_border,
-                    sample_value,
-                    distance_to_edge: 0.0, // Sera calculé après
-                },
-            );
-        }
+            CellData {
+                coord,
+                terrain_type,
+                is_border,
+                sample_value,
+                distance_to_edge: 0.0, // Sera calculé après
+            }
+        });
 
         // Calculer les distances aux bords
         self.calculate_distance_fields();
@@ -168,102 +853,254 @@ impl OrganicContourSystem {
 
     // Calcule le champ de distance pour smooth les transitions
     fn calculate_distance_fields(&mut self) {
-        // Simplified distance field - en production utiliser jump flooding
-        for hex in self.hex_cells.keys().cloned().collect::<Vec<_>>() {
-            if let Some(cell) = self.hex_cells.get(&hex) {
-                if cell.is_border {
-                    // Les cellules frontières ont distance 0
-                    self.hex_cells.get_mut(&hex).unwrap().distance_to_edge = 0.0;
-                } else {
-                    // Trouver la distance au bord le plus proche
-                    let mut min_dist = f32::MAX;
-                    for other_hex in self.hex_cells.keys() {
-                        if self.hex_cells[other_hex].is_border {
-                            let dist = hex.unsigned_distance_to(*other_hex) as f32;
-                            min_dist = min_dist.min(dist);
-                        }
-                    }
-                    self.hex_cells.get_mut(&hex).unwrap().distance_to_edge = min_dist;
+        // BFS multi-sources depuis les cellules frontières (distance hexagonale),
+        // sur les indices denses de la grille
+        let mut distances = self.hex_cells.par_map(|_, cell| {
+            if cell.is_border { 0.0 } else { f32::MAX }
+        });
+        let mut queue: VecDeque<usize> = distances
+            .values()
+            .enumerate()
+            .filter(|(_, distance)| **distance == 0.0)
+            .map(|(index, _)| index)
+            .collect();
+
+        while let Some(index) = queue.pop_front() {
+            let next_distance = distances.as_slice()[index] + 1.0;
+            for neighbor in self.hex_cells.neighbor_indices(index) {
+                let distance = &mut distances.as_mut_slice()[neighbor];
+                if *distance > next_distance {
+                    *distance = next_distance;
+                    queue.push_back(neighbor);
                 }
             }
         }
+
+        self.hex_cells
+            .par_iter_mut()
+            .zip(distances.par_iter())
+            .for_each(|((_, cell), (_, distance))| cell.distance_to_edge = *distance);
+    }
+
+    // Distance signée à la côte d'un hexagone (positive sur terre, négative en mer).
+    // La côte passe entre les cellules frontières, d'où le demi-hexagone.
+    fn signed_distance(&self, hex: Hex) -> Option<f32> {
+        let cell = self.hex_cells.get(&HexCoord::from_hex(hex))?;
+        let distance = cell.distance_to_edge + 0.5;
+        if cell.sample_value > self.config.threshold {
+            Some(distance)
+        } else {
+            Some(-distance)
+        }
+    }
+
+    // Champ de distance signé continu : moyenne pondérée de l'hexagone et de ses voisins
+    fn sample_signed_distance(&self, world_pos: Vec2) -> f32 {
+        let hex = self.hex_layout.world_pos_to_hex(world_pos);
+        let hex_size = self.hex_layout.scale.max(Vec2::splat(f32::EPSILON));
+
+        let mut total = 0.0;
+        let mut total_weight = 0.0;
+        for candidate in std::iter::once(hex).chain(hex.all_neighbors()) {
+            let Some(value) = self.signed_distance(candidate) else {
+                continue;
+            };
+            let offset = (self.hex_layout.hex_to_world_pos(candidate) - world_pos) / hex_size;
+            let weight = (-offset.length_squared()).exp();
+            total += value * weight;
+            total_weight += weight;
+        }
+
+        if total_weight > 0.0 {
+            total / total_weight
+        } else {
+            OPEN_SEA_DISTANCE
+        }
     }
 
     // Méthode alternative : générer des contours globaux avec marching squares
     pub fn generate_organic_contours_global(&self) -> Vec<ContourPath> {
-        // Créer une grille régulière pour l'échantillonnage
-        let grid_size = 2000; // Résolution de la grille d'échantillonnage
-        let bounds = self.calculate_bounds();
-        let cell_size = Vec2::new(
-            (bounds.1.x - bounds.0.x) / grid_size as f32,
-            (bounds.1.y - bounds.0.y) / grid_size as f32,
-        );
+        self.extract_contours_global()
+            .into_iter()
+            .map(|c| self.smooth_contour_with_splines(c))
+            .collect()
+    }
+
+    // Contours bruts du marching squares, connectés mais non lissés
+    pub fn extract_contours_global(&self) -> Vec<ContourPath> {
+        let grid = self.marching_grid();
+        let segments = self.coastline_segments(&grid, CellWindow::full(&grid));
+        self.connect_cell_segments(&segments)
+    }
+
+    /// Grille du marching squares du trait de côte
+    pub fn marching_grid(&self) -> MarchingGrid {
+        MarchingGrid {
+            resolution: self.marching_resolution,
+            ..MarchingGrid::new(&self.hex_layout, self.hex_cells.coords())
+        }
+    }
 
-        // Échantillonner la binary map + bruit sur une grille régulière
-        let mut grid_values = vec![vec![0.0; grid_size + 1]; grid_size + 1];
+    // Segments du trait de côte des cellules de la fenêtre
+    fn coastline_segments(&self, grid: &MarchingGrid, window: CellWindow) -> CellSegments {
+        let bounds = (grid.min, grid.max);
+        let cell_size = grid.cell_size();
+        let columns = window.max.0 - window.min.0 + 1;
 
-        for y in 0..=grid_size {
-            for x in 0..=grid_size {
+        // Échantillonner la binary map + bruit aux coins des cellules de la fenêtre
+        let mut grid_values = Vec::with_capacity(columns * (window.max.1 - window.min.1 + 1));
+        for y in window.min.1..=window.max.1 {
+            for x in window.min.0..=window.max.0 {
                 let world_pos = Vec2::new(
                     bounds.0.x + x as f32 * cell_size.x,
                     bounds.0.y + y as f32 * cell_size.y,
                 );
 
                 // Échantillonner la binary map avec bruit fractal
-                let img_x = (world_pos.x * self.config.pixels_per_hex
-                    + self.binary_map.width() as f32 / 2.0);
-                let img_y = (world_pos.y * self.config.pixels_per_hex
-                    + self.binary_map.height() as f32 / 2.0);
+                grid_values.push(self.coastline_field(world_pos));
+            }
+        }
+        let value =
+            |x: usize, y: usize| grid_values[(y - window.min.1) * columns + x - window.min.0];
 
-                let base_value = self.sample_at_position(img_x, img_y);
-                let noise =
-                    self.fractal_noise(world_pos.x, world_pos.y) * self.config.noise_amplitude;
+        // Appliquer marching squares
+        let mut segments = Vec::new();
+        for y in window.min.1..window.max.1 {
+            for x in window.min.0..window.max.0 {
+                let corners = [
+                    value(x, y),
+                    value(x + 1, y),
+                    value(x + 1, y + 1),
+                    value(x, y + 1),
+                ];
 
-                grid_values[y][x] = base_value + noise;
+                if let Some(cell_segments) = self.marching_square_cell(
+                    x,
+                    y,
+                    &corners,
+                    cell_size,
+                    bounds.0,
+                    self.config.threshold,
+                ) {
+                    let cell = grid.cell_index(x, y);
+                    segments.extend(cell_segments.into_iter().map(|segment| (cell, segment)));
+                }
             }
         }
 
-        // Appliquer marching squares
-        let mut contours = Vec::new();
+        segments
+    }
+
+    // Connecte les segments dans l'ordre des cellules
+    fn connect_cell_segments(&self, segments: &CellSegments) -> Vec<ContourPath> {
+        self.connect_segments_to_contours(segments.iter().map(|(_, segment)| *segment).collect())
+    }
+
+    // Isobathes et courbes de niveau lissées
+    pub fn generate_iso_contours(&self) -> Vec<IsoContour> {
+        self.extract_iso_contours()
+            .into_iter()
+            .map(|iso| self.smooth_iso_contour(iso))
+            .collect()
+    }
+
+    pub fn smooth_iso_contour(&self, iso: IsoContour) -> IsoContour {
+        IsoContour {
+            path: self.smooth_contour_with_splines(iso.path),
+            ..iso
+        }
+    }
+
+    // Extrait toutes les isobathes et courbes de niveau en une seule passe
+    // sur le champ de distance signé à la côte (sans lissage)
+    pub fn extract_iso_contours(&self) -> Vec<IsoContour> {
+        let grid = self.iso_grid();
+        let segments = self.iso_segments(&grid, CellWindow::full(&grid));
+        self.connect_iso_segments(&segments)
+    }
+
+    // Niveaux des isobathes puis des courbes de niveau
+    fn iso_levels(&self) -> Vec<(f32, IsoContourKind)> {
+        self.config
+            .depth_levels
+            .iter()
+            .map(|&level| (level, IsoContourKind::Depth))
+            .chain(
+                self.config
+                    .elevation_levels
+                    .iter()
+                    .map(|&level| (level, IsoContourKind::Elevation)),
+            )
+            .collect()
+    }
+
+    // Grille du marching squares des iso-contours
+    fn iso_grid(&self) -> MarchingGrid {
+        let (min, max) = self.calculate_bounds();
+        MarchingGrid {
+            min,
+            max,
+            resolution: self.config.iso_grid_size.max(1),
+        }
+    }
+
+    // Segments de chaque niveau pour les cellules de la fenêtre
+    fn iso_segments(&self, grid: &MarchingGrid, window: CellWindow) -> Vec<CellSegments> {
+        let levels = self.iso_levels();
+        let mut segments_per_level = vec![Vec::new(); levels.len()];
+        if levels.is_empty() {
+            return segments_per_level;
+        }
+
+        let bounds = (grid.min, grid.max);
+        let cell_size = grid.cell_size();
+        let columns = window.max.0 - window.min.0 + 1;
 
-        for y in 0..grid_size {
-            for x in 0..grid_size {
+        // Échantillonner le champ une seule fois pour tous les niveaux
+        let mut grid_values = Vec::with_capacity(columns * (window.max.1 - window.min.1 + 1));
+        for y in window.min.1..=window.max.1 {
+            for x in window.min.0..=window.max.0 {
+                let world_pos = bounds.0 + Vec2::new(x as f32, y as f32) * cell_size;
+                grid_values.push(self.sample_signed_distance(world_pos));
+            }
+        }
+        let value =
+            |x: usize, y: usize| grid_values[(y - window.min.1) * columns + x - window.min.0];
+
+        for y in window.min.1..window.max.1 {
+            for x in window.min.0..window.max.0 {
                 let corners = [
-                    grid_values[y][x],
-                    grid_values[y][x + 1],
-                    grid_values[y + 1][x + 1],
-                    grid_values[y + 1][x],
+                    value(x, y),
+                    value(x + 1, y),
+                    value(x + 1, y + 1),
+                    value(x, y + 1),
                 ];
 
-                if let Some(segments) =
-                    self.marching_square_cell(x, y, &corners, cell_size, bounds.0)
-                {
-                    for segment in segments {
-                        contours.push(ContourPath {
-                            points: vec![segment.0, segment.1],
-                            is_closed: false,
-                        });
+                for (i, (level, _)) in levels.iter().enumerate() {
+                    if let Some(segments) =
+                        self.marching_square_cell(x, y, &corners, cell_size, bounds.0, *level)
+                    {
+                        let cell = grid.cell_index(x, y);
+                        segments_per_level[i]
+                            .extend(segments.into_iter().map(|segment| (cell, segment)));
                     }
                 }
             }
         }
 
-        // Connecter et lisser les contours
-        let connected = self.connect_segments_to_contours(
-            contours
-                .into_iter()
-                .flat_map(|c| {
-                    c.points
-                        .windows(2)
-                        .map(|w| (w[0], w[1]))
-                        .collect::<Vec<_>>()
-                })
-                .collect(),
-        );
+        segments_per_level
+    }
 
-        connected
+    // Connecte les segments de chaque niveau en iso-contours
+    fn connect_iso_segments(&self, segments_per_level: &[CellSegments]) -> Vec<IsoContour> {
+        self.iso_levels()
             .into_iter()
-            .map(|c| self.smooth_contour_with_splines(c))
+            .zip(segments_per_level)
+            .flat_map(|((level, kind), segments)| {
+                self.connect_cell_segments(segments)
+                    .into_iter()
+                    .map(move |path| IsoContour { level, kind, path })
+            })
             .collect()
     }
 
@@ -273,13 +1110,13 @@ impl OrganicContourSystem {
         let mut visited = HashMap::new();
 
         // Pour chaque cellule frontière, générer un contour
-        for (hex, cell) in &self.hex_cells {
+        for (coord, cell) in self.hex_cells.iter() {
             if !cell.is_border {
                 continue;
             }
 
             // Convertir l'hexagone en 6 triangles via la grille duale
-            let triangles = self.hex_to_triangular_dual(*hex);
+            let triangles = self.hex_to_triangular_dual(coord.to_hex());
 
             for triangle in triangles {
                 if visited.contains_key(&triangle.id) {
@@ -306,17 +1143,7 @@ impl OrganicContourSystem {
 
     // Calcule les limites de la carte
     fn calculate_bounds(&self) -> (Vec2, Vec2) {
-        let mut min = Vec2::new(f32::MAX, f32::MAX);
-        let mut max = Vec2::new(f32::MIN, f32::MIN);
-
-        for hex in self.hex_cells.keys() {
-            let pos = self.hex_layout.hex_to_world_pos(*hex);
-            let size = self.hex_layout.scale;
-            min = min.min(pos - size);
-            max = max.max(pos + size);
-        }
-
-        (min, max)
+        map_bounds(&self.hex_layout, self.hex_cells.coords())
     }
 
     // Marching squares pour une cellule
@@ -327,8 +1154,8 @@ impl OrganicContourSystem {
         corners: &[f32; 4],
         cell_size: Vec2,
         offset: Vec2,
+        threshold: f32,
     ) -> Option<Vec<(Vec2, Vec2)>> {
-        let threshold = self.config.threshold;
 
         // Classification de la cellule
         let case = (if corners[0] > threshold { 1 } else { 0 })
@@ -456,7 +1283,7 @@ impl OrganicContourSystem {
             }
 
             // Si le contour n'est pas fermé mais a une longueur suffisante
-            if path.len() > 2 && !contours.last().map_or(false, |c| c.is_closed) {
+            if path.len() > 2 && !contours.last().is_some_and(|c| c.is_closed) {
                 contours.push(ContourPath {
                     points: path,
                     is_closed: false,
@@ -514,26 +1341,12 @@ impl OrganicContourSystem {
             .collect();
 
         // Marching triangles simplifié
-        self.marching_triangle(&triangle, &values)
+        self.marching_triangle(triangle, &values)
     }
 
     // Bruit fractal pour rendre les contours organiques
     fn fractal_noise(&self, x: f32, y: f32) -> f32 {
-        let mut value = 0.0;
-        let mut amplitude = 1.0;
-        let mut frequency = self.config.noise_frequency;
-
-        for _ in 0..self.config.noise_octaves {
-            value += self
-                .perlin
-                .get([x as f64 * frequency as f64, y as f64 * frequency as f64])
-                as f32
-                * amplitude;
-            amplitude *= 0.5;
-            frequency *= 2.0;
-        }
-
-        value
+        self.noise.sample(Vec2::new(x, y))
     }
 
     // Échantillonne la binary map à une position précise avec interpolation bilinéaire
@@ -701,90 +1514,131 @@ impl OrganicContourSystem {
         *p1 + (*p2 - *p1) * t.clamp(0.0, 1.0)
     }
 
-    // Lisse un contour avec des splines de Catmull-Rom
-    fn smooth_contour_with_splines(&self, contour: ContourPath) -> ContourPath {
-        if contour.points.len() < 4 {
-            return contour;
-        }
-
-        let mut smoothed_points = Vec::new();
-        let segments = 10; // Nombre de subdivisions par segment
-
-        for i in 0..contour.points.len() {
-            let p0 = if i == 0 {
-                contour.points[contour.points.len() - 1]
-            } else {
-                contour.points[i - 1]
-            };
-
-            let p1 = contour.points[i];
-            let p2 = contour.points[(i + 1) % contour.points.len()];
-            let p3 = contour.points[(i + 2) % contour.points.len()];
-
-            // Générer les points de la spline
-            for j in 0..segments {
-                let t = j as f32 / segments as f32;
-                let point = self.catmull_rom_point(p0, p1, p2, p3, t);
-                smoothed_points.push(point);
-            }
-        }
-
-        ContourPath {
-            points: smoothed_points,
-            is_closed: contour.is_closed,
-        }
+    // Lisse un contour selon la stratégie configurée
+    pub fn smooth_contour_with_splines(&self, contour: ContourPath) -> ContourPath {
+        contour.smooth(self.config.smoothing, self.config.spline_tension)
     }
 
-    // Calcul d'un point sur une spline de Catmull-Rom
-    fn catmull_rom_point(&self, p0: Vec2, p1: Vec2, p2: Vec2, p3: Vec2, t: f32) -> Vec2 {
-        let tau = self.config.spline_tension;
-
-        let t2 = t * t;
-        let t3 = t2 * t;
+    // Génère un mesh simplifié par échelle de caméra configurée.
+    // Une échelle orthographique correspond à des unités monde par pixel écran,
+    // la tolérance monde d'un niveau est donc tolérance_pixels * échelle.
+    pub fn build_lod_mesh_data(&self, contours: &[ContourPath]) -> Vec<(f32, TerrainMeshData)> {
+        let mut scales = self.config.lod_scales.clone();
+        scales.sort_by(f32::total_cmp);
 
-        let v0 = (p2 - p0) * tau;
-        let v1 = (p3 - p1) * tau;
-
-        let a = p1 * 2.0 - p2 * 2.0 + v0 + v1;
-        let b = -p1 * 3.0 + p2 * 3.0 - v0 * 2.0 - v1;
-        let c = v0;
-        let d = p1;
+        scales
+            .into_iter()
+            .map(|max_scale| {
+                let tolerance = self.config.lod_pixel_tolerance * max_scale;
+                let simplified: Vec<ContourPath> =
+                    contours.iter().map(|c| c.simplify(tolerance)).collect();
+
+                let data = self.build_mesh_data(&simplified);
+                info!(
+                    "LOD (échelle <= {}): {} vertices",
+                    max_scale,
+                    data.vertices.len()
+                );
 
-        a * t3 + b * t2 + c * t + d
+                (max_scale, data)
+            })
+            .collect()
     }
 
     // Génère le mesh final pour le rendu
     pub fn generate_mesh(&self, contours: &[ContourPath]) -> Mesh {
-        let mut vertices = Vec::new();
-        let mut indices = Vec::new();
-        let mut normals = Vec::new();
-        let mut uvs = Vec::new();
+        self.build_mesh_data(contours).into_mesh()
+    }
 
+    // Données du mesh plein (terre, bandes d'eau), réutilisables pour les exports
+    pub fn build_mesh_data(&self, contours: &[ContourPath]) -> TerrainMeshData {
         // Générer un mesh plein pour toutes les cellules de terre
         // Trianguler chaque contour fermé (îles de terre)
-        for contour in contours {
-            if contour.points.len() < 3 {
-                continue;
+        let polygons = contours
+            .iter()
+            .filter(|contour| contour.points.len() >= 3)
+            .map(|contour| self.triangulate_contour(contour))
+            .collect();
+
+        utilities::merge_mesh_data(polygons)
+    }
+
+    // Mesh plein d'un contour, repris de la génération précédente quand le
+    // terrain est résident et que le contour n'a pas changé
+    fn triangulate_contour(&self, contour: &ContourPath) -> TerrainMeshData {
+        let mut memo = self
+            .triangulations
+            .lock()
+            .expect("Mémo des triangulations empoisonné");
+        let key = memo
+            .enabled
+            .then(|| TriangulationMemo::key(&contour.points));
+
+        if let Some(key) = key {
+            if let Some(data) = memo.current.get(&key) {
+                return data.clone();
+            }
+            if let Some(data) = memo.previous.remove(&key) {
+                memo.current.insert(key, data.clone());
+                return data;
             }
+        }
+
+        // Utiliser ear clipping pour trianguler le polygone
+        let mut data = TerrainMeshData::default();
+        self.triangulate_polygon(
+            &contour.points,
+            &mut data.vertices,
+            &mut data.indices,
+            &mut data.normals,
+            &mut data.uvs,
+        );
 
-            // Utiliser ear clipping pour trianguler le polygone
-            self.triangulate_polygon(
-                &contour.points,
-                &mut vertices,
-                &mut indices,
-                &mut normals,
-                &mut uvs,
-            );
+        if let Some(key) = key {
+            memo.current.insert(key, data.clone());
         }
+        data
+    }
 
-        Mesh::new(
-            PrimitiveTopology::TriangleList,
-            RenderAssetUsages::RENDER_WORLD,
-        )
-        .with_inserted_attribute(Mesh::ATTRIBUTE_POSITION, vertices)
-        .with_inserted_attribute(Mesh::ATTRIBUTE_NORMAL, normals)
-        .with_inserted_attribute(Mesh::ATTRIBUTE_UV_0, uvs)
-        .with_inserted_indices(Indices::U16(indices))
+    // Remplit les isobathes fermées, de la plus profonde à la plus proche de la côte.
+    // Chaque bande est légèrement surélevée pour recouvrir la précédente.
+    pub fn build_water_bands(&self, iso_contours: &[IsoContour]) -> TerrainMeshData {
+        let mut levels = self.config.depth_levels.clone();
+        levels.sort_by(f32::total_cmp);
+
+        let deep = DEEP_WATER_COLOR.to_srgba();
+        let shallow = SHALLOW_WATER_COLOR.to_srgba();
+
+        let bands = levels
+            .iter()
+            .enumerate()
+            .map(|(i, level)| {
+                let paths: Vec<ContourPath> = iso_contours
+                    .iter()
+                    .filter(|iso| {
+                        iso.kind == IsoContourKind::Depth
+                            && iso.level == *level
+                            && iso.path.is_closed
+                    })
+                    .map(|iso| iso.path.clone())
+                    .collect();
+
+                let t = (i + 1) as f32 / levels.len() as f32;
+                let color = Color::srgb(
+                    deep.red + (shallow.red - deep.red) * t,
+                    deep.green + (shallow.green - deep.green) * t,
+                    deep.blue + (shallow.blue - deep.blue) * t,
+                );
+
+                let mut band = self.build_mesh_data(&paths).with_color(color);
+                for vertex in &mut band.vertices {
+                    vertex[2] = i as f32 * 0.01;
+                }
+                band
+            })
+            .collect();
+
+        utilities::merge_mesh_data(bands)
     }
 
     // Triangulation par ear clipping pour les polygones organiques
@@ -792,7 +1646,7 @@ impl OrganicContourSystem {
         &self,
         points: &[Vec2],
         vertices: &mut Vec<[f32; 3]>,
-        indices: &mut Vec<u16>,
+        indices: &mut Vec<u32>,
         normals: &mut Vec<[f32; 3]>,
         uvs: &mut Vec<[f32; 2]>,
     ) {
@@ -800,7 +1654,21 @@ impl OrganicContourSystem {
             return;
         }
 
-        let base_index = vertices.len() as u16;
+        let base_index = u32::try_from(vertices.len()).expect("Trop de vertices pour des indices u32");
+
+        // L'ear clipping attend un polygone orienté CCW
+        let signed_area: f32 = points
+            .iter()
+            .zip(points.iter().cycle().skip(1))
+            .map(|(a, b)| a.perp_dot(*b))
+            .sum();
+        let reversed: Vec<Vec2>;
+        let points = if signed_area < 0.0 {
+            reversed = points.iter().rev().copied().collect();
+            &reversed[..]
+        } else {
+            points
+        };
 
         // Calculer le centre et les bounds pour les UVs
         let mut center = Vec2::ZERO;
@@ -836,11 +1704,11 @@ impl OrganicContourSystem {
                 let curr = remaining[i];
                 let next = remaining[(i + 1) % remaining.len()];
 
-                if self.is_ear(&points, prev, curr, next, &remaining) {
+                if self.is_ear(points, prev, curr, next, &remaining) {
                     // Créer le triangle
-                    indices.push(base_index + prev as u16);
-                    indices.push(base_index + curr as u16);
-                    indices.push(base_index + next as u16);
+                    indices.push(base_index + prev as u32);
+                    indices.push(base_index + curr as u32);
+                    indices.push(base_index + next as u32);
 
                     // Retirer le sommet du milieu
                     remaining.remove(i);
@@ -851,18 +1719,18 @@ impl OrganicContourSystem {
 
             // Sécurité: si aucune oreille n'est trouvée, forcer la triangulation
             if !ear_found && remaining.len() > 3 {
-                indices.push(base_index + remaining[0] as u16);
-                indices.push(base_index + remaining[1] as u16);
-                indices.push(base_index + remaining[2] as u16);
+                indices.push(base_index + remaining[0] as u32);
+                indices.push(base_index + remaining[1] as u32);
+                indices.push(base_index + remaining[2] as u32);
                 remaining.remove(1);
             }
         }
 
         // Ajouter le dernier triangle
         if remaining.len() == 3 {
-            indices.push(base_index + remaining[0] as u16);
-            indices.push(base_index + remaining[1] as u16);
-            indices.push(base_index + remaining[2] as u16);
+            indices.push(base_index + remaining[0] as u32);
+            indices.push(base_index + remaining[1] as u32);
+            indices.push(base_index + remaining[2] as u32);
         }
     }
 
@@ -918,38 +1786,61 @@ impl OrganicContourSystem {
         (u >= 0.0) && (v >= 0.0) && (u + v <= 1.0)
     }
 
+    // Génère un mesh de lignes fines (iso-contours, contours de côte)
+    pub fn generate_line_mesh(&self, contours: &[ContourPath], thickness: f32) -> Mesh {
+        self.build_line_mesh_data(contours, thickness).into_mesh()
+    }
+
+    pub fn build_line_mesh_data(&self, contours: &[ContourPath], thickness: f32) -> TerrainMeshData {
+        let mut data = TerrainMeshData::default();
+
+        self.add_contour_overlay(
+            &mut data.vertices,
+            &mut data.indices,
+            &mut data.normals,
+            &mut data.uvs,
+            contours,
+            thickness,
+        );
+
+        data
+    }
+
     // Ajoute une bordure visible pour les contours (optionnel)
     fn add_contour_overlay(
         &self,
         vertices: &mut Vec<[f32; 3]>,
-        indices: &mut Vec<u16>,
+        indices: &mut Vec<u32>,
         normals: &mut Vec<[f32; 3]>,
         uvs: &mut Vec<[f32; 2]>,
         contours: &[ContourPath],
+        thickness: f32,
     ) {
         let elevation = 0.01; // Légèrement au-dessus pour éviter z-fighting
-        let thickness = 0.05; // Épaisseur de la bordure
+        let half_thickness = thickness * 0.5;
 
         for contour in contours {
             if contour.points.len() < 2 {
                 continue;
             }
 
-            let base_index = vertices.len() as u16;
+            let base_index =
+                u32::try_from(vertices.len()).expect("Trop de vertices pour des indices u32");
+            let count = contour.points.len();
 
             for (i, point) in contour.points.iter().enumerate() {
-                let next = if i < contour.points.len() - 1 {
-                    contour.points[i + 1]
+                // Direction du segment suivant (ou précédent pour la fin d'un chemin ouvert)
+                let direction = if i < count - 1 {
+                    contour.points[i + 1] - *point
                 } else if contour.is_closed {
-                    contour.points[0]
+                    contour.points[0] - *point
                 } else {
-                    continue;
-                };
-
-                let direction = (next - *point).normalize();
-                let perpendicular = Vec2::new(-direction.y, direction.x) * thickness;
+                    *point - contour.points[i - 1]
+                }
+                .normalize_or_zero();
+                let perpendicular = Vec2::new(-direction.y, direction.x) * half_thickness;
 
-                // Quatre vertices pour un segment de ligne épais
+                // Deux vertices par point pour un segment de ligne épais
                 vertices.push([
                     point.x - perpendicular.x,
                     point.y - perpendicular.y,
@@ -964,13 +1855,13 @@ impl OrganicContourSystem {
                 normals.push([0.0, 0.0, 1.0]);
                 normals.push([0.0, 0.0, 1.0]);
 
-                let t = i as f32 / contour.points.len() as f32;
+                let t = i as f32 / count as f32;
                 uvs.push([t, 0.0]);
                 uvs.push([t, 1.0]);
 
-                // Créer les triangles si on a au moins 4 vertices
+                // Relier au point précédent
                 if i > 0 {
-                    let idx = base_index + (i * 2) as u16;
+                    let idx = base_index + (i * 2) as u32;
                     // Premier triangle
                     indices.push(idx - 2);
                     indices.push(idx - 1);
@@ -983,8 +1874,8 @@ impl OrganicContourSystem {
             }
 
             // Fermer le contour si nécessaire
-            if contour.is_closed && contour.points.len() > 2 {
-                let last_idx = base_index + ((contour.points.len() - 1) * 2) as u16;
+            if contour.is_closed && count > 2 {
+                let last_idx = base_index + ((count - 1) * 2) as u32;
                 indices.push(last_idx);
                 indices.push(last_idx + 1);
                 indices.push(base_index);
@@ -996,3 +1887,196 @@ impl OrganicContourSystem {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::hex::rendering::contour::terrain::MeshDataError;
+    use crate::hex::rendering::config::GAME_HEX_RADIUS;
+
+    // Système sans grille : seuls les constructeurs de meshes sont utilisés
+    fn mesh_builder() -> OrganicContourSystem {
+        let layout = HexConfig::game(GAME_HEX_RADIUS).layout;
+        OrganicContourSystem::new(DynamicImage::new_rgba8(4, 4), layout, organic_contour_config())
+    }
+
+    // Polygone régulier fermé de `count` sommets
+    fn polygon(center: Vec2, radius: f32, count: usize) -> ContourPath {
+        ContourPath {
+            points: (0..count)
+                .map(|i| center + Vec2::from_angle(i as f32 / count as f32 * std::f32::consts::TAU) * radius)
+                .collect(),
+            is_closed: true,
+        }
+    }
+
+    // Pipeline de terrain_cli, sans fenêtre ni GPU
+    fn generate_headless(map: &str, radius: u32) -> (TerrainContours, TerrainMeshes) {
+        let binary_map = image::open(map).expect("binary map de test");
+        let layout = HexConfig::game(GAME_HEX_RADIUS).layout;
+        let mut system = OrganicContourSystem::new(binary_map, layout, organic_contour_config());
+        system.initialize_hex_grid(radius);
+        assert_eq!(system.hex_cells().len(), 3 * radius as usize * (radius as usize + 1) + 1);
+        system.generate()
+    }
+
+    #[test]
+    fn painted_beach_stays_below_threshold() {
+        let binary_map =
+            image::open("assets/maps/binary_map_tiny.png").expect("binary map de test");
+        let layout = HexConfig::game(GAME_HEX_RADIUS).layout;
+        let mut system = OrganicContourSystem::new(binary_map, layout, organic_contour_config());
+        system.initialize_hex_grid(8);
+
+        let threshold = system.config.threshold;
+        let cells = system.hex_cells();
+        // Terre entourée de terre, et eau
+        let land = cells
+            .iter()
+            .find(|(coord, cell)| {
+                cell.terrain_type == TerrainType::Land
+                    && cells.neighbors(coord).count() == 6
+                    && cells
+                        .neighbors(coord)
+                        .all(|(_, n)| n.terrain_type.is_land())
+            })
+            .map(|(coord, _)| coord)
+            .expect("cellule de terre intérieure");
+        let water = cells
+            .iter()
+            .find(|(_, cell)| !cell.terrain_type.is_land())
+            .map(|(coord, _)| coord)
+            .expect("cellule d'eau");
+
+        system.apply_overrides(&HashMap::from([
+            (land, TerrainType::Beach),
+            (water, TerrainType::Land),
+        ]));
+        let cells = system.hex_cells();
+
+        let beach = &cells[land];
+        assert_eq!(beach.terrain_type, TerrainType::Beach);
+        assert!(beach.sample_value <= threshold);
+        // La terre voisine borde maintenant l'eau
+        for (_, neighbor) in cells.neighbors(&land) {
+            assert_eq!(neighbor.terrain_type, TerrainType::Cliff);
+        }
+
+        assert_eq!(cells[water].terrain_type, TerrainType::Land);
+        assert!(cells[water].sample_value > threshold);
+    }
+
+    // Repeindre la carte résidente donne le même terrain qu'une génération
+    // complète. Grille du trait de côte réduite : deux générations en debug.
+    #[test]
+    fn live_update_matches_full_generation() {
+        let live_terrain = |overrides: &HashMap<HexCoord, TerrainType>| {
+            let binary_map =
+                image::open("assets/maps/binary_map_tiny.png").expect("binary map de test");
+            let layout = HexConfig::game(GAME_HEX_RADIUS).layout;
+            let mut system =
+                OrganicContourSystem::new(binary_map, layout, organic_contour_config())
+                    .with_marching_resolution(400);
+            system.initialize_hex_grid(8);
+            LiveTerrain::new(0, system, overrides)
+        };
+        let paint = |q, r, terrain_type| (HexCoord::new(q, r), terrain_type);
+
+        let (mut live, _) = live_terrain(&HashMap::from([
+            paint(0, 0, TerrainType::Land),
+            paint(2, -1, TerrainType::Land),
+            paint(-3, 1, TerrainType::DeepWater),
+        ]));
+
+        // Une cellule gardée, une repeinte, une effacée et une nouvelle
+        let repainted = HashMap::from([
+            paint(0, 0, TerrainType::Land),
+            paint(2, -1, TerrainType::ShallowWater),
+            paint(5, -5, TerrainType::Land),
+        ]);
+        let updated = live.update(&repainted);
+        let (_, expected) = live_terrain(&repainted);
+
+        let bytes =
+            |terrain: &TerrainCache| bincode::serialize(terrain).expect("terrain sérialisable");
+        assert!(!updated.contours.coastline.is_empty());
+        assert_eq!(bytes(&updated), bytes(&expected));
+    }
+
+    #[test]
+    fn headless_generation_produces_valid_meshes() {
+        let (contours, meshes) = generate_headless("assets/maps/binary_map_tiny.png", 8);
+
+        assert!(!contours.coastline.is_empty());
+        assert_eq!(meshes.validate(), Ok(()));
+        assert!(!meshes.land.indices.is_empty());
+    }
+
+    // Les meshes de Gaulyia dépassent 65 535 vertices : des indices u16
+    // reboucleraient en triangles aberrants
+    #[test]
+    #[ignore = "carte complète, lent en debug : cargo test --release -- --ignored"]
+    fn gaulyia_meshes_use_u32_indices_past_u16_limit() {
+        let map_source = MapSource::default();
+        let (_, meshes) = generate_headless(&map_source.image_path, map_source.hex_radius);
+
+        assert_eq!(meshes.validate(), Ok(()));
+        let max_vertices = meshes
+            .named()
+            .iter()
+            .map(|(_, data)| data.vertices.len())
+            .max()
+            .unwrap_or(0);
+        assert!(max_vertices > u16::MAX as usize, "{max_vertices} vertices");
+        assert!(
+            meshes
+                .named()
+                .iter()
+                .flat_map(|(_, data)| &data.indices)
+                .any(|&index| index > u16::MAX as u32)
+        );
+    }
+
+    // 300 îles de 256 sommets : plus de 65 535 vertices, là où des indices
+    // u16 reboucleraient en triangles aberrants
+    #[test]
+    fn meshes_past_u16_limit_keep_valid_indices() {
+        let system = mesh_builder();
+        let islands: Vec<ContourPath> = (0..300)
+            .map(|i| polygon(Vec2::new((i % 20) as f32, (i / 20) as f32) * 100.0, 40.0, 256))
+            .collect();
+
+        let land = system.build_mesh_data(&islands).with_color(LAND_COLOR);
+        let outline = system.build_line_mesh_data(&islands, 2.0);
+        let merged = utilities::merge_mesh_data(vec![land.clone(), outline.clone()]);
+
+        for data in [&land, &outline, &merged] {
+            assert!(data.vertices.len() > u16::MAX as usize, "{} vertices", data.vertices.len());
+            assert!(data.indices.iter().any(|&index| index > u16::MAX as u32));
+            assert_eq!(data.validate(), Ok(()));
+        }
+        assert_eq!(merged.colors.len(), merged.vertices.len());
+    }
+
+    #[test]
+    fn validate_rejects_broken_meshes() {
+        let mut data = mesh_builder().build_mesh_data(&[polygon(Vec2::ZERO, 10.0, 6)]);
+        assert_eq!(data.validate(), Ok(()));
+
+        let vertices = data.vertices.len();
+        data.indices[0] = vertices as u32;
+        assert_eq!(
+            data.validate(),
+            Err(MeshDataError::IndexOutOfBounds {
+                index: vertices as u32,
+                vertices
+            })
+        );
+
+        data.indices.pop();
+        assert!(matches!(
+            data.validate(),
+            Err(MeshDataError::IncompleteTriangle { .. })
+        ));
+    }
+}
@@ -1,28 +1,22 @@
 use bevy::prelude::*;
-use bevy::tasks::{AsyncComputeTaskPool, Task};
+use bevy::tasks::AsyncComputeTaskPool;
 use hexx::*;
-use image::{DynamicImage, GenericImageView, Rgba};
+use image::DynamicImage;
 use rayon::prelude::*;
 use std::sync::{Arc, Mutex};
 
 use super::super::terrain::{
-    GenerationStage, TerrainGenerationProgress, TerrainMeshData, TerrainSettings,
+    GenerationProgressHandle, GenerationStage, TerrainGenerationProgress, TerrainGenerationTask,
+    TerrainMeshData, TerrainSettings,
 };
 use super::super::{ContourConfig, ContourPath};
+use super::organic_contour_system::organic_contour_config;
 use super::utilities;
 
 use crate::hex::HexConfig;
 
-#[derive(Resource)]
-struct TerrainGenerationTask(Task<TerrainMeshData>);
-
-#[derive(Resource)]
-struct GenerationProgressHandle(Arc<Mutex<TerrainGenerationProgress>>);
-
 pub fn start_terrain_generation(
     mut commands: Commands,
-    asset_server: Res<AssetServer>,
-    thread_pool: Res<AsyncComputeTaskPool>,
     hex_config: Res<HexConfig>,
     terrain_settings: Res<TerrainSettings>,
 ) {
@@ -37,14 +31,13 @@ pub fn start_terrain_generation(
     let image_path = "assets/maps/Gaulyia_binarymap.png";
     let binary_map = image::open(image_path).expect("Failed to load binary map image");
 
-    // Configuration
+    // Configuration basse résolution, lissage et tension partagés avec la
+    // génération principale
     let config = ContourConfig {
         pixels_per_hex: 0.25 * terrain_settings.low_res_scale,
         noise_amplitude: 0.3,
-        noise_frequency: 2.0,
         noise_octaves: 3,
-        threshold: 0.5,
-        spline_tension: 0.5,
+        ..organic_contour_config()
     };
 
     let layout = hex_config.layout.clone();
@@ -60,7 +53,7 @@ pub fn start_terrain_generation(
     let progress_clone = progress.clone();
     let settings_clone = terrain_settings.clone();
 
-    let task = thread_pool.spawn(async move {
+    let task = AsyncComputeTaskPool::get().spawn(async move {
         generate_terrain_async(
             binary_map,
             config,
@@ -120,13 +113,16 @@ async fn generate_terrain_async(
         "Génération des contours...",
     );
 
-    let high_res_data = upscale_terrain_data(
+    let upscaled = upscale_terrain_data(
         low_res_data,
         settings.low_res_scale,
         settings.upscale_smoothing,
         &progress,
     );
 
+    // Lissage configuré, indépendant du lissage de l'agrandissement
+    let high_res_data = smooth_contours_parallel(upscaled, &config, &progress);
+
     // Phase 3: Triangulation finale
     utilities::update_progress(
         &progress,
@@ -213,7 +209,7 @@ fn upscale_terrain_data(
     let total = low_res_contours.len();
 
     // Paralléliser l'upscaling des contours
-    let upscaled: Vec<ContourPath> = low_res_contours
+    low_res_contours
         .par_iter()
         .enumerate()
         .map(|(i, contour)| {
@@ -233,19 +229,11 @@ fn upscale_terrain_data(
                 let p2 = window[1] * inv_scale;
 
                 if smooth {
-                    // Subdiviser et lisser
+                    // Subdiviser : le lissage configuré courbe ensuite les points
                     let subdivisions = 4;
                     for j in 0..subdivisions {
                         let t = j as f32 / subdivisions as f32;
-                        let interpolated = p1 + (p2 - p1) * t;
-
-                        // Ajouter une petite perturbation pour plus de naturel
-                        let noise = Vec2::new(
-                            (i * 1000 + j * 10) as f32 * 0.001 % 1.0 - 0.5,
-                            (i * 1000 + j * 10 + 1) as f32 * 0.001 % 1.0 - 0.5,
-                        ) * 0.1;
-
-                        new_points.push(interpolated + noise);
+                        new_points.push(p1 + (p2 - p1) * t);
                     }
                 } else {
                     new_points.push(p1);
@@ -262,20 +250,14 @@ fn upscale_terrain_data(
                 is_closed: contour.is_closed,
             }
         })
-        .collect();
-
-    // Appliquer un lissage supplémentaire si demandé
-    if smooth {
-        smooth_contours_parallel(upscaled, progress)
-    } else {
-        upscaled
-    }
+        .collect()
 }
 
 // =================== LISSAGE PARALLÈLE ===================
 
 fn smooth_contours_parallel(
     contours: Vec<ContourPath>,
+    config: &ContourConfig,
     progress: &Arc<Mutex<TerrainGenerationProgress>>,
 ) -> Vec<ContourPath> {
     let total = contours.len();
@@ -293,37 +275,11 @@ fn smooth_contours_parallel(
                 );
             }
 
-            apply_catmull_rom_smoothing(contour)
+            contour.smooth(config.smoothing, config.spline_tension)
         })
         .collect()
 }
 
-fn apply_catmull_rom_smoothing(contour: &ContourPath) -> ContourPath {
-    if contour.points.len() < 4 {
-        return contour.clone();
-    }
-
-    let mut smoothed = Vec::new();
-    let segments = 5;
-
-    for i in 0..contour.points.len() {
-        let p0 = contour.points[(i + contour.points.len() - 1) % contour.points.len()];
-        let p1 = contour.points[i];
-        let p2 = contour.points[(i + 1) % contour.points.len()];
-        let p3 = contour.points[(i + 2) % contour.points.len()];
-
-        for j in 0..segments {
-            let t = j as f32 / segments as f32;
-            smoothed.push(utilities::catmull_rom(p0, p1, p2, p3, t, 0.5));
-        }
-    }
-
-    ContourPath {
-        points: smoothed,
-        is_closed: contour.is_closed,
-    }
-}
-
 // =================== TRIANGULATION PARALLÈLE ===================
 
 fn triangulate_mesh(
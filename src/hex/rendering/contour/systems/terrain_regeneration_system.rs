@@ -0,0 +1,178 @@
+use bevy::prelude::*;
+use bevy::tasks::{AsyncComputeTaskPool, Task};
+use futures_lite::future;
+use std::collections::HashMap;
+use std::io;
+
+use super::super::terrain::TerrainMesh;
+use super::organic_contour_system::{LiveTerrain, load_or_generate_terrain, spawn_terrain};
+use crate::hex::rendering::contour::ContourConfig;
+use crate::hex::{HexConfig, HexCoord};
+use crate::input::{ActionInput, InputAction};
+use crate::shared::types::TerrainType;
+use crate::state::cache::TerrainCache;
+use crate::state::components::{MapSource, TerrainOverrides};
+
+// Délai sans nouvelle modification avant de relancer la génération
+const REGENERATION_DELAY: f32 = 0.3;
+
+// Terrain généré, avec le terrain résident quand la carte est éditée
+type RegenerationResult = io::Result<(Option<LiveTerrain>, TerrainCache)>;
+
+/// Suivi de la régénération du terrain après édition ou changement de carte
+#[derive(Resource)]
+pub struct TerrainRegeneration {
+    // Entrées du terrain actuellement affiché (ou en cours de génération)
+    applied_hex_config: HexConfig,
+    applied_source: MapSource,
+    applied_overrides: HashMap<HexCoord, TerrainType>,
+    config: ContourConfig,
+    pending: bool,
+    debounce: Timer,
+    // Terrain résident, déplacé dans la tâche le temps de la génération
+    live: Option<LiveTerrain>,
+    task: Option<Task<RegenerationResult>>,
+}
+
+impl TerrainRegeneration {
+    pub fn new(hex_config: HexConfig, map_source: MapSource, config: ContourConfig) -> Self {
+        Self {
+            applied_hex_config: hex_config,
+            applied_source: map_source,
+            applied_overrides: HashMap::new(),
+            config,
+            pending: false,
+            debounce: Timer::from_seconds(REGENERATION_DELAY, TimerMode::Once),
+            live: None,
+            task: None,
+        }
+    }
+
+    pub fn is_running(&self) -> bool {
+        self.task.is_some()
+    }
+}
+
+/// Programme une régénération quand les cellules peintes, la carte ou la
+/// HexConfig changent (chargement d'une sauvegarde)
+pub fn schedule_terrain_regeneration(
+    regeneration: Option<ResMut<TerrainRegeneration>>,
+    hex_config: Res<HexConfig>,
+    overrides: Option<Res<TerrainOverrides>>,
+    map_source: Res<MapSource>,
+) {
+    // Les cellules modifiées appartiennent à EditorPlugin
+    let (Some(mut regeneration), Some(overrides)) = (regeneration, overrides) else {
+        return;
+    };
+    if !overrides.is_changed() && !map_source.is_changed() && !hex_config.is_changed() {
+        return;
+    }
+
+    if overrides.cells != regeneration.applied_overrides
+        || *map_source != regeneration.applied_source
+        || *hex_config != regeneration.applied_hex_config
+    {
+        regeneration.pending = true;
+        regeneration.debounce.reset();
+    }
+}
+
+/// Lance la génération en tâche de fond une fois l'édition en pause
+pub fn start_terrain_regeneration(
+    regeneration: Option<ResMut<TerrainRegeneration>>,
+    time: Res<Time>,
+    input: ActionInput,
+    hex_config: Res<HexConfig>,
+    overrides: Option<Res<TerrainOverrides>>,
+    map_source: Res<MapSource>,
+) {
+    let (Some(mut regeneration), Some(overrides)) = (regeneration, overrides) else {
+        return;
+    };
+    if !regeneration.pending || regeneration.is_running() {
+        return;
+    }
+
+    regeneration.debounce.tick(time.delta());
+    // Pas de régénération au milieu d'un coup de pinceau
+    if !regeneration.debounce.is_finished() || input.pressed(InputAction::Select) {
+        return;
+    }
+
+    regeneration.pending = false;
+    // Le terrain résident ne sert que pour la même carte et la même HexConfig
+    let same_source = *map_source == regeneration.applied_source
+        && *hex_config == regeneration.applied_hex_config;
+    let live = regeneration.live.take().filter(|_| same_source);
+    regeneration.applied_hex_config = hex_config.clone();
+    regeneration.applied_source = map_source.clone();
+    regeneration.applied_overrides = overrides.cells.clone();
+
+    let hex_config = hex_config.clone();
+    let map_source = map_source.clone();
+    let config = regeneration.config.clone();
+    let overrides = overrides.cells.clone();
+    let modified = overrides.len();
+    let task = AsyncComputeTaskPool::get().spawn(async move {
+        match live {
+            Some(mut live) => {
+                let terrain = live.update(&overrides);
+                Ok((Some(live), terrain))
+            }
+            // Carte d'origine : le cache disque suffit
+            None if overrides.is_empty() => {
+                load_or_generate_terrain(&hex_config, &map_source, &config)
+                    .map(|terrain| (None, terrain))
+            }
+            None => LiveTerrain::load(&hex_config, &map_source, &config, &overrides)
+                .map(|(live, terrain)| (Some(live), terrain)),
+        }
+    });
+
+    regeneration.task = Some(task);
+    info!("⏳ Régénération du terrain ({} cellules modifiées)", modified);
+}
+
+/// Remplace les meshes du terrain quand la génération est terminée
+pub fn finish_terrain_regeneration(
+    mut commands: Commands,
+    regeneration: Option<ResMut<TerrainRegeneration>>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<ColorMaterial>>,
+    terrain_entities: Query<Entity, With<TerrainMesh>>,
+) {
+    let Some(mut regeneration) = regeneration else {
+        return;
+    };
+    let Some(task) = regeneration.task.as_mut() else {
+        return;
+    };
+    let Some(result) = future::block_on(future::poll_once(task)) else {
+        return;
+    };
+    regeneration.task = None;
+    let terrain = match result {
+        Ok((live, terrain)) => {
+            regeneration.live = live;
+            terrain
+        }
+        Err(err) => {
+            error!(
+                "Échec de la régénération du terrain ({}) : {}",
+                regeneration.applied_source.image_path, err
+            );
+            return;
+        }
+    };
+
+    for entity in &terrain_entities {
+        commands.entity(entity).despawn();
+    }
+
+    info!(
+        "✓ Terrain régénéré ({} contours)",
+        terrain.contours.coastline.len()
+    );
+    spawn_terrain(&mut commands, &mut meshes, &mut materials, terrain, &regeneration.config);
+}
@@ -1,5 +1,5 @@
 use hexx::*;
-use image::{DynamicImage, GenericImageView, Rgba};
+use image::{DynamicImage, GenericImageView};
 use std::sync::{Arc, Mutex};
 
 use super::super::terrain::{GenerationStage, TerrainGenerationProgress, TerrainMeshData};
@@ -38,26 +38,11 @@ pub fn sample_binary_map_for_hex(
     pixel[0] as f32 / 255.0
 }
 
-pub fn catmull_rom(p0: Vec2, p1: Vec2, p2: Vec2, p3: Vec2, t: f32, tension: f32) -> Vec2 {
-    let t2 = t * t;
-    let t3 = t2 * t;
-
-    let v0 = (p2 - p0) * tension;
-    let v1 = (p3 - p1) * tension;
-
-    let a = p1 * 2.0 - p2 * 2.0 + v0 + v1;
-    let b = -p1 * 3.0 + p2 * 3.0 - v0 * 2.0 - v1;
-    let c = v0;
-    let d = p1;
-
-    a * t3 + b * t2 + c * t + d
-}
-
-pub fn triangulate_single_contour(contour: &ContourPath) -> TerrainMeshData {
-    let mut vertices = Vec::new();
-    let mut indices = Vec::new();
-    let mut normals = Vec::new();
-    let mut uvs = Vec::new();
+pub fn triangulate_single_contour(_contour: &ContourPath) -> TerrainMeshData {
+    let vertices = Vec::new();
+    let indices = Vec::new();
+    let normals = Vec::new();
+    let uvs = Vec::new();
 
     // Implémentation simplifiée d'ear clipping
     // ... (voir l'artifact précédent pour l'implémentation complète)
@@ -67,20 +52,29 @@ pub fn triangulate_single_contour(contour: &ContourPath) -> TerrainMeshData {
         indices,
         normals,
         uvs,
+        ..Default::default()
     }
 }
 
 pub fn merge_mesh_data(meshes: Vec<TerrainMeshData>) -> TerrainMeshData {
-    let mut merged = TerrainMeshData {
-        vertices: Vec::new(),
-        indices: Vec::new(),
-        normals: Vec::new(),
-        uvs: Vec::new(),
-    };
+    let mut merged = TerrainMeshData::default();
+    // Dès qu'un mesh est coloré, chaque vertex fusionné doit avoir une couleur
+    let colored = meshes.iter().any(|mesh| !mesh.colors.is_empty());
 
     for mesh in meshes {
-        let base_index = merged.vertices.len() as u16;
-
+        let base_index = u32::try_from(merged.vertices.len())
+            .expect("Trop de vertices pour des indices u32");
+
+        if colored {
+            if mesh.colors.len() == mesh.vertices.len() {
+                merged.colors.extend(mesh.colors);
+            } else {
+                // Blanc : couleur neutre pour le matériau
+                merged
+                    .colors
+                    .extend(std::iter::repeat_n([1.0; 4], mesh.vertices.len()));
+            }
+        }
         merged.vertices.extend(mesh.vertices);
         merged.normals.extend(mesh.normals);
         merged.uvs.extend(mesh.uvs);
@@ -94,11 +88,17 @@ pub fn merge_mesh_data(meshes: Vec<TerrainMeshData>) -> TerrainMeshData {
     merged
 }
 
+/// Vérifie que tous les indices pointent sur un vertex existant
+/// (détecte un débordement d'indice lors de la fusion de meshes)
+pub fn indices_in_bounds(indices: &[u32], vertex_count: usize) -> bool {
+    indices.iter().all(|&idx| (idx as usize) < vertex_count)
+}
+
 pub fn generate_contours_from_samples(
-    samples: Vec<(Hex, f32)>,
-    layout: &HexLayout,
-    config: &ContourConfig,
-    progress: &Arc<Mutex<TerrainGenerationProgress>>,
+    _samples: Vec<(Hex, f32)>,
+    _layout: &HexLayout,
+    _config: &ContourConfig,
+    _progress: &Arc<Mutex<TerrainGenerationProgress>>,
 ) -> Vec<ContourPath> {
     // Implémentation simplifiée
     Vec::new()
@@ -3,10 +3,13 @@ use std::sync::{Arc, Mutex};
 
 use super::GenerationStage;
 
-#[derive(Resource)]
+#[derive(Resource, Clone)]
 pub struct TerrainGenerationProgress {
     pub stage: GenerationStage,
     pub progress: f32,
     pub message: String,
 }
 
+/// Progression partagée avec la tâche de génération asynchrone
+#[derive(Resource)]
+pub struct GenerationProgressHandle(pub Arc<Mutex<TerrainGenerationProgress>>);
@@ -1,5 +1,3 @@
-use bevy::prelude::*;
-
 #[derive(Clone, Debug)]
 pub enum GenerationStage {
     Idle,
@@ -4,4 +4,4 @@ use bevy::tasks::Task;
 use super::mesh_data::TerrainMeshData;
 
 #[derive(Resource)]
-pub struct TerrainGenerationTask(Task<TerrainMeshData>);
+pub struct TerrainGenerationTask(pub Task<TerrainMeshData>);
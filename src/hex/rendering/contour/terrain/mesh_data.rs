@@ -1,8 +1,106 @@
+use bevy::asset::RenderAssetUsages;
+use bevy::mesh::Indices;
+use bevy::prelude::*;
+use bevy::render::render_resource::PrimitiveTopology;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
 // Structure pour passer les données entre threads
-#[derive(Clone)]
+#[derive(Clone, Default, Serialize, Deserialize)]
 pub struct TerrainMeshData {
     pub vertices: Vec<[f32; 3]>,
-    pub indices: Vec<u16>,
+    pub indices: Vec<u32>,
     pub normals: Vec<[f32; 3]>,
     pub uvs: Vec<[f32; 2]>,
-}
\ No newline at end of file
+    pub colors: Vec<[f32; 4]>,
+}
+
+/// Incohérence détectée par `TerrainMeshData::validate`
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MeshDataError {
+    // Le nombre d'indices n'est pas un multiple de 3
+    IncompleteTriangle { indices: usize },
+    IndexOutOfBounds { index: u32, vertices: usize },
+    // Normales, UVs ou couleurs en nombre différent des vertices
+    AttributeCount {
+        attribute: &'static str,
+        count: usize,
+        vertices: usize,
+    },
+}
+
+impl fmt::Display for MeshDataError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MeshDataError::IncompleteTriangle { indices } => {
+                write!(f, "{indices} indices, triangle incomplet")
+            }
+            MeshDataError::IndexOutOfBounds { index, vertices } => {
+                write!(f, "indice {index} hors des bornes ({vertices} vertices)")
+            }
+            MeshDataError::AttributeCount {
+                attribute,
+                count,
+                vertices,
+            } => write!(f, "{count} {attribute} pour {vertices} vertices"),
+        }
+    }
+}
+
+impl std::error::Error for MeshDataError {}
+
+impl TerrainMeshData {
+    /// Vérifie les triangles, les indices et le nombre de chaque attribut
+    /// (couleurs optionnelles) avant rendu, export ou mise en cache
+    pub fn validate(&self) -> Result<(), MeshDataError> {
+        let vertices = self.vertices.len();
+        if !self.indices.len().is_multiple_of(3) {
+            return Err(MeshDataError::IncompleteTriangle {
+                indices: self.indices.len(),
+            });
+        }
+        if let Some(&index) = self.indices.iter().find(|&&index| index as usize >= vertices) {
+            return Err(MeshDataError::IndexOutOfBounds { index, vertices });
+        }
+
+        for (attribute, count) in [
+            ("normales", self.normals.len()),
+            ("uvs", self.uvs.len()),
+            ("couleurs", self.colors.len()),
+        ] {
+            if count != vertices && !(attribute == "couleurs" && count == 0) {
+                return Err(MeshDataError::AttributeCount {
+                    attribute,
+                    count,
+                    vertices,
+                });
+            }
+        }
+        Ok(())
+    }
+
+    /// Applique une couleur de vertex uniforme
+    pub fn with_color(mut self, color: Color) -> Self {
+        let color = color.to_linear().to_f32_array();
+        self.colors = vec![color; self.vertices.len()];
+        self
+    }
+
+    /// Convertit en mesh Bevy (indices u32)
+    pub fn into_mesh(self) -> Mesh {
+        let mut mesh = Mesh::new(
+            PrimitiveTopology::TriangleList,
+            RenderAssetUsages::RENDER_WORLD,
+        )
+        .with_inserted_attribute(Mesh::ATTRIBUTE_POSITION, self.vertices)
+        .with_inserted_attribute(Mesh::ATTRIBUTE_NORMAL, self.normals)
+        .with_inserted_attribute(Mesh::ATTRIBUTE_UV_0, self.uvs)
+        .with_inserted_indices(Indices::U32(self.indices));
+
+        if !self.colors.is_empty() {
+            mesh.insert_attribute(Mesh::ATTRIBUTE_COLOR, self.colors);
+        }
+
+        mesh
+    }
+}
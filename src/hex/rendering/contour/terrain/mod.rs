@@ -3,9 +3,13 @@ mod generation_stage;
 mod generation_task;
 mod mesh_data;
 mod settings;
+mod terrain_cells;
+mod terrain_meshes;
 
 pub use generation_progress::{GenerationProgressHandle, TerrainGenerationProgress};
 pub use generation_stage::GenerationStage;
 pub use generation_task::TerrainGenerationTask;
-pub use mesh_data::TerrainMeshData;
-pub use settings::TerrainSettings;
\ No newline at end of file
+pub use mesh_data::{MeshDataError, TerrainMeshData};
+pub use settings::TerrainSettings;
+pub use terrain_cells::TerrainCells;
+pub use terrain_meshes::{TerrainMesh, TerrainMeshes};
\ No newline at end of file
@@ -0,0 +1,64 @@
+use bevy::prelude::*;
+use hexx::HexLayout;
+use std::collections::HashMap;
+
+use crate::hex::{HexCoord, HexMap};
+use crate::shared::types::{CellData, TerrainType};
+
+/// Cellules du terrain généré, interrogeables par coordonnée
+#[derive(Resource, Clone, Default)]
+pub struct TerrainCells {
+    pub cells: HexMap<CellData>,
+    // Terrain généré des cellules peintes depuis la dernière génération
+    generated: HashMap<HexCoord, (TerrainType, f32)>,
+}
+
+impl TerrainCells {
+    pub fn from_cells(cells: HexMap<CellData>) -> Self {
+        Self {
+            cells,
+            generated: HashMap::new(),
+        }
+    }
+
+    pub fn get(&self, coord: &HexCoord) -> Option<&CellData> {
+        self.cells.get(coord)
+    }
+
+    /// Cellule sous une position monde
+    pub fn at_world_pos(&self, layout: &HexLayout, world_pos: Vec2) -> Option<&CellData> {
+        self.get(&HexCoord::from_hex(layout.world_pos_to_hex(world_pos)))
+    }
+
+    pub fn contains(&self, coord: &HexCoord) -> bool {
+        self.cells.contains(coord)
+    }
+
+    pub fn terrain_type(&self, coord: &HexCoord) -> Option<TerrainType> {
+        self.cells.get(coord).map(|cell| cell.terrain_type)
+    }
+
+    /// Peint une cellule en attendant la régénération complète du terrain.
+    /// `None` restaure le terrain généré s'il est encore connu.
+    pub fn paint(&mut self, coord: &HexCoord, terrain_type: Option<TerrainType>) {
+        let Some(cell) = self.cells.get_mut(coord) else {
+            return;
+        };
+
+        match terrain_type {
+            Some(terrain_type) => {
+                self.generated
+                    .entry(*coord)
+                    .or_insert((cell.terrain_type, cell.sample_value));
+                cell.terrain_type = terrain_type;
+                cell.sample_value = if terrain_type.is_land() { 1.0 } else { 0.0 };
+            }
+            None => {
+                if let Some((terrain_type, sample_value)) = self.generated.remove(coord) {
+                    cell.terrain_type = terrain_type;
+                    cell.sample_value = sample_value;
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,47 @@
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use super::{MeshDataError, TerrainMeshData};
+
+/// Entités de rendu du terrain, remplacées à chaque régénération
+#[derive(Component)]
+pub struct TerrainMesh;
+
+/// Données des meshes générés, conservées pour les exports glTF/OBJ
+#[derive(Resource, Clone, Default, Serialize, Deserialize)]
+pub struct TerrainMeshes {
+    pub land: TerrainMeshData,
+    // Lacs et mers intérieures, dessinés au-dessus de la terre
+    pub lakes: TerrainMeshData,
+    pub water_bands: TerrainMeshData,
+    pub outline: TerrainMeshData,
+    // Terre simplifiée par niveau de détail (échelle max, mesh)
+    pub lod_levels: Vec<(f32, TerrainMeshData)>,
+    pub depth_lines: TerrainMeshData,
+    pub elevation_lines: TerrainMeshData,
+}
+
+impl TerrainMeshes {
+    /// Meshes nommés, dans l'ordre d'export
+    pub fn named(&self) -> [(&'static str, &TerrainMeshData); 4] {
+        [
+            ("land", &self.land),
+            ("lakes", &self.lakes),
+            ("water_bands", &self.water_bands),
+            ("outline", &self.outline),
+        ]
+    }
+
+    /// Premier mesh incohérent, niveaux de détail et lignes compris
+    pub fn validate(&self) -> Result<(), (&'static str, MeshDataError)> {
+        let named = self.named().into_iter().chain([
+            ("depth_lines", &self.depth_lines),
+            ("elevation_lines", &self.elevation_lines),
+        ]);
+        let lods = self.lod_levels.iter().map(|(_, data)| ("lod", data));
+        for (name, data) in named.chain(lods) {
+            data.validate().map_err(|err| (name, err))?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,32 @@
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use super::{ContourPath, IsoContour};
+
+/// Contours générés, conservés après le spawn des meshes (exports, debug)
+#[derive(Resource, Clone, Default, Serialize, Deserialize)]
+pub struct TerrainContours {
+    // Côtes issues du marching squares, avant lissage
+    pub raw_coastline: Vec<ContourPath>,
+    pub coastline: Vec<ContourPath>,
+    pub raw_iso_contours: Vec<IsoContour>,
+    pub iso_contours: Vec<IsoContour>,
+}
+
+impl TerrainContours {
+    pub fn coastline(&self, smoothed: bool) -> &[ContourPath] {
+        if smoothed {
+            &self.coastline
+        } else {
+            &self.raw_coastline
+        }
+    }
+
+    pub fn iso_contours(&self, smoothed: bool) -> &[IsoContour] {
+        if smoothed {
+            &self.iso_contours
+        } else {
+            &self.raw_iso_contours
+        }
+    }
+}
@@ -8,12 +8,12 @@ pub struct HexRenderingPlugin;
 
 impl Plugin for HexRenderingPlugin {
     fn build(&self, app: &mut App) {
-        app.add_systems(
+        app.add_plugins(contour::OrganicContourPlugin)
+            .add_systems(
             Startup,
             (
                 systems::setup_hex_config,
                 atlas::systems::setup_materials,
-                contour::systems::setup_organic_contour,
             )
                 .chain(),
         );
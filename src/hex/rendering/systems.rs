@@ -1,27 +1,25 @@
 use std::collections::HashSet;
 
 use bevy::prelude::*;
-use hexx::{HexOrientation, shapes};
+use hexx::shapes;
 
 use super::atlas::ColorTintMaterials;
-use super::components::{HexTile, HexVisuals};
-use super::config::HexConfig;
+use super::components::{HexTerrain, HexTile, HexVisuals};
+use super::config::{GAME_HEX_RADIUS, HexConfig};
 use crate::camera::MainCamera;
+use crate::editor::{EditCommand, EditRequest};
 use crate::hex::HexCoord;
+use crate::input::{InputAction, InputMap};
 use crate::shared::types::ChunkId;
 use crate::state::cache::WorldCache;
 
 pub fn setup_hex_config(mut commands: Commands) {
-    let radius = 48.;
-    let orientation = HexOrientation::Flat;
-    let ratio = Vec2::new(1.0, 0.67);
-    let chunk_size = 10 as u8;
-    let config = HexConfig::new(radius, orientation, ratio, chunk_size);
-    commands.insert_resource(config);
+    let config = HexConfig::game(GAME_HEX_RADIUS);
     info!(
         "✓ HexConfig configuré (rayon: {}, orientation: {:?}, ratio: {:?})",
-        radius, orientation, ratio
+        config.hex_radius, config.orientation, config.ratio
     );
+    commands.insert_resource(config);
 }
 
 pub fn render_visible_chunks(
@@ -36,7 +34,7 @@ pub fn render_visible_chunks(
     for chunk_id in &world_cache.chunks {
         spawn_chunk(
             &mut commands,
-            &chunk_id,
+            chunk_id,
             &hex_config,
             &color_tint_materials,
             existing_coords.clone(),
@@ -59,7 +57,7 @@ pub fn unload_distant_chunks(
     }
 
     for chunk_id in unloaded_chunks {
-        world_cache.unload_chunk(chunk_id.clone());
+        world_cache.unload_chunk(chunk_id);
     }
 }
 
@@ -216,7 +214,7 @@ pub fn spawn_hex_sprite(
             Visibility::Inherited,
         ))
         .observe(update_material_on::<Pointer<Over>>(hover_material.clone()))
-        .observe(update_material_on::<Pointer<Out>>(material.clone()))
+        .observe(restore_base_material_on::<Pointer<Out>>)
         .observe(click_handler)
         .observe(update_material_on::<Pointer<Press>>(
             pressed_material.clone(),
@@ -228,31 +226,14 @@ pub fn spawn_hex_sprite(
 
 fn click_handler(
     click: On<Pointer<Click>>,
-    mut commands: Commands,
+    mut edits: MessageWriter<EditRequest>,
     camera_query: Query<(&Camera, &GlobalTransform), With<MainCamera>>,
     hex_config: Res<HexConfig>,
-    existing: Query<(Entity, &HexTile)>,
+    existing: Query<&HexTile>,
+    input_map: Res<InputMap>,
 ) {
-    if click.button == PointerButton::Primary {
-        let click_position = click.pointer_location.position;
-        info!("Clicked at: {}", click_position);
-        let Ok((camera, camera_transform)) = camera_query.single() else {
-            return;
-        };
-        if let Ok(world_pos) = camera.viewport_to_world_2d(camera_transform, click_position) {
-            let clicked_hex = hex_config.layout.world_pos_to_hex(world_pos);
-            let clicked_hex_coord = HexCoord::from_hex(clicked_hex);
-            let lower_res_hex = clicked_hex.to_lower_res(hex_config.chunk_size as u32);
-            let lower_res_hex_coord = HexCoord::from_hex(lower_res_hex);
-
-            let center_hex = lower_res_hex.to_higher_res(hex_config.chunk_size as u32);
-            let center_hex_coord = HexCoord::from_hex(center_hex);
-
-            info!("Clicked hex: {:?}", clicked_hex_coord);
-            info!("   > lower res: {:?}", lower_res_hex_coord);
-            info!("   > center   : {:?}", center_hex_coord);
-        }
-    } else if click.button == PointerButton::Secondary {
+    // Les clics sont publiés en `HexClicked` par HexPointerPlugin
+    if input_map.binds_pointer(InputAction::DespawnChunk, click.button) {
         // commands.entity(click.event_target()).despawn();
         let Ok((camera, camera_transform)) = camera_query.single() else {
             return;
@@ -261,31 +242,43 @@ fn click_handler(
         let click_position = click.pointer_location.position;
         if let Ok(world_pos) = camera.viewport_to_world_2d(camera_transform, click_position) {
             let clicked_hex = hex_config.layout.world_pos_to_hex(world_pos);
-            let chunk_hex = clicked_hex.to_lower_res(hex_config.chunk_size as u32);
-            let center_hex = chunk_hex.to_higher_res(hex_config.chunk_size as u32);
+            let chunk_id =
+                ChunkId::from_hex_coord(HexCoord::from_hex(clicked_hex), hex_config.chunk_size);
 
-            info!("Despawn chunk: {:?}", HexCoord::from_hex(chunk_hex));
+            info!("Despawn chunk: {:?}", chunk_id.coord);
 
-            let hex_shape = shapes::Hexagon {
-                center: center_hex,
-                radius: hex_config.chunk_size as u32,
-            };
+            let shape_coords: std::collections::HashSet<_> =
+                chunk_id.hex_coords().into_iter().collect();
 
-            let shape_coords: std::collections::HashSet<_> = hex_shape.coords().collect();
-
-            let entities: Vec<Entity> = existing
+            // Tuiles présentes, pour pouvoir les respawner à l'annulation
+            let coords: Vec<HexCoord> = existing
                 .iter()
-                .filter(|(_, hex_tile)| shape_coords.contains(&hex_tile.coord.to_hex()))
-                .map(|(entity, _)| entity)
+                .map(|hex_tile| hex_tile.coord)
+                .filter(|coord| shape_coords.contains(coord))
                 .collect();
 
-            for entity in entities {
-                commands.entity(entity).despawn();
-            }
+            edits.write(EditRequest::new(EditCommand::DespawnChunk {
+                chunk: chunk_id,
+                coords,
+            }));
         }
     }
 }
 
+// Remet le matériau de repos de la tuile : celui de son terrain s'il a été peint
+fn restore_base_material_on<E: EntityEvent>(
+    event: On<E>,
+    color_tint_materials: Res<ColorTintMaterials>,
+    mut query: Query<(&mut MeshMaterial2d<ColorMaterial>, Option<&HexTerrain>)>,
+) {
+    if let Ok((mut material, terrain)) = query.get_mut(event.event_target()) {
+        material.0 = match terrain {
+            Some(HexTerrain(terrain_type)) => color_tint_materials.terrain_material(*terrain_type),
+            None => color_tint_materials.get_material("default".to_string()),
+        };
+    }
+}
+
 fn update_material_on<E: EntityEvent>(
     new_material: Handle<ColorMaterial>,
 ) -> impl Fn(On<E>, Query<&mut MeshMaterial2d<ColorMaterial>>) {
@@ -0,0 +1,122 @@
+use bevy::ecs::system::SystemParam;
+use bevy::input::keyboard::Key;
+use bevy::prelude::*;
+use bevy_inspector_egui::bevy_egui::input::EguiWantsInput;
+
+use super::{InputAction, InputBinding, InputMap, KeyModifier};
+
+/// État des actions d'après l'`InputMap`, toutes manettes confondues.
+/// Les boutons de souris sont ignorés quand le pointeur est sur l'UI egui.
+#[derive(SystemParam)]
+pub struct ActionInput<'w, 's> {
+    map: Res<'w, InputMap>,
+    keys: Res<'w, ButtonInput<KeyCode>>,
+    logical_keys: Res<'w, ButtonInput<Key>>,
+    mouse: Res<'w, ButtonInput<MouseButton>>,
+    gamepads: Query<'w, 's, &'static Gamepad>,
+    egui: Option<Res<'w, EguiWantsInput>>,
+}
+
+impl ActionInput<'_, '_> {
+    pub fn map(&self) -> &InputMap {
+        &self.map
+    }
+
+    pub fn pressed(&self, action: InputAction) -> bool {
+        self.value(action) > 0.0
+    }
+
+    /// Les axes analogiques ne déclenchent pas d'appui ponctuel
+    pub fn just_pressed(&self, action: InputAction) -> bool {
+        self.map
+            .bindings(action)
+            .iter()
+            .any(|binding| self.binding_just_pressed(binding))
+    }
+
+    /// Vrai si egui utilise le pointeur (survol d'un panneau, widget glissé).
+    /// Un glissé commencé hors de l'UI reste au monde.
+    pub fn pointer_over_ui(&self) -> bool {
+        self.egui
+            .as_ref()
+            .is_some_and(|egui| egui.wants_pointer_input())
+    }
+
+    pub fn modifier_pressed(&self, modifier: KeyModifier) -> bool {
+        self.keys.any_pressed(modifier.keys())
+    }
+
+    /// Intensité de l'action dans [0, 1] : 1 pour une touche enfoncée,
+    /// position du stick au-delà de la zone morte pour un axe
+    pub fn value(&self, action: InputAction) -> f32 {
+        self.map
+            .bindings(action)
+            .iter()
+            .map(|binding| self.binding_value(binding))
+            .fold(0.0, f32::max)
+    }
+
+    /// `positive` moins `negative`, dans [-1, 1]
+    pub fn axis(&self, negative: InputAction, positive: InputAction) -> f32 {
+        self.value(positive) - self.value(negative)
+    }
+
+    fn binding_just_pressed(&self, binding: &InputBinding) -> bool {
+        match binding {
+            InputBinding::Key(code) => self.keys.just_pressed(*code),
+            InputBinding::Char(character) => {
+                character_keys(character).any(|key| self.logical_keys.just_pressed(key))
+            }
+            InputBinding::Mouse(button) => {
+                !self.pointer_over_ui() && self.mouse.just_pressed(*button)
+            }
+            InputBinding::Gamepad(button) => {
+                self.gamepads.iter().any(|gamepad| gamepad.just_pressed(*button))
+            }
+            InputBinding::GamepadAxis { .. } => false,
+            InputBinding::Modified { modifier, binding } => {
+                self.modifier_pressed(*modifier) && self.binding_just_pressed(binding)
+            }
+        }
+    }
+
+    fn binding_value(&self, binding: &InputBinding) -> f32 {
+        let pressed = match binding {
+            InputBinding::Key(code) => self.keys.pressed(*code),
+            InputBinding::Char(character) => {
+                character_keys(character).any(|key| self.logical_keys.pressed(key))
+            }
+            InputBinding::Mouse(button) => !self.pointer_over_ui() && self.mouse.pressed(*button),
+            InputBinding::Gamepad(button) => {
+                self.gamepads.iter().any(|gamepad| gamepad.pressed(*button))
+            }
+            InputBinding::GamepadAxis { axis, direction } => {
+                return self
+                    .gamepads
+                    .iter()
+                    .filter_map(|gamepad| gamepad.get(*axis))
+                    .map(|value| (value * direction.sign()).clamp(0.0, 1.0))
+                    .filter(|value| *value > self.map.dead_zone)
+                    .fold(0.0, f32::max);
+            }
+            InputBinding::Modified { modifier, binding } => {
+                return if self.modifier_pressed(*modifier) {
+                    self.binding_value(binding)
+                } else {
+                    0.0
+                };
+            }
+        };
+        if pressed { 1.0 } else { 0.0 }
+    }
+}
+
+// Le caractère produit dépend de Maj : minuscule et majuscule sont acceptées
+fn character_keys(character: &str) -> impl Iterator<Item = Key> {
+    let lower = character.to_lowercase();
+    let upper = character.to_uppercase();
+    let upper = (upper != lower).then_some(upper);
+    std::iter::once(lower)
+        .chain(upper)
+        .map(|character| Key::Character(character.into()))
+}
@@ -0,0 +1,82 @@
+use serde::{Deserialize, Serialize};
+
+/// Actions de la caméra et de l'éditeur, indépendantes des touches
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum InputAction {
+    PanUp,
+    PanDown,
+    PanLeft,
+    PanRight,
+    ZoomIn,
+    ZoomOut,
+    // Déplacer la carte en glissant
+    DragPan,
+    // Clic principal : peindre, placer, choisir un point
+    Select,
+    DespawnChunk,
+    ToggleEditor,
+    BrushTool,
+    LineTool,
+    FillTool,
+    BrushSmaller,
+    BrushLarger,
+    Cancel,
+    ToggleMinimap,
+    // Terrain peint en mode édition, dans l'ordre de `TerrainType::ALL`
+    Terrain1,
+    Terrain2,
+    Terrain3,
+    Terrain4,
+    Terrain5,
+    // Ctrl+Z et Ctrl+Y par défaut, Ctrl+Maj+Z rétablit aussi
+    Undo,
+    Redo,
+    // Retour au signet, enregistrement avec Ctrl
+    Bookmark1,
+    Bookmark2,
+    Bookmark3,
+    Bookmark4,
+    Bookmark5,
+    Bookmark6,
+    Bookmark7,
+    Bookmark8,
+    Bookmark9,
+    // Enregistre la session d'édition, la rejoue avec Maj
+    EditSession,
+    TogglePathDebug,
+    QuickSave,
+    QuickLoad,
+    ExportSvg,
+    ExportMeshes,
+    // Overlays de debug
+    ToggleChunkDebug,
+    ToggleHexDebug,
+    CyclePickingDebug,
+    ToggleCoordDebug,
+    ToggleContourDebug,
+    // Contours bruts ou lissés
+    ToggleRawContours,
+    ToggleMarchingGridDebug,
+}
+
+impl InputAction {
+    pub const TERRAINS: [InputAction; 5] = [
+        InputAction::Terrain1,
+        InputAction::Terrain2,
+        InputAction::Terrain3,
+        InputAction::Terrain4,
+        InputAction::Terrain5,
+    ];
+
+    pub const BOOKMARKS: [InputAction; 9] = [
+        InputAction::Bookmark1,
+        InputAction::Bookmark2,
+        InputAction::Bookmark3,
+        InputAction::Bookmark4,
+        InputAction::Bookmark5,
+        InputAction::Bookmark6,
+        InputAction::Bookmark7,
+        InputAction::Bookmark8,
+        InputAction::Bookmark9,
+    ];
+}
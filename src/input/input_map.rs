@@ -0,0 +1,277 @@
+use bevy::input::gamepad::{GamepadAxis, GamepadButton};
+use bevy::picking::pointer::PointerButton;
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fs;
+use std::io;
+use std::path::Path;
+
+use super::InputAction;
+
+/// Entrée physique déclenchant une action
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub enum InputBinding {
+    // Position de la touche, indépendante de la disposition (KeyW = Z en AZERTY)
+    Key(KeyCode),
+    // Caractère produit par la touche, selon la disposition du clavier
+    Char(String),
+    Mouse(MouseButton),
+    Gamepad(GamepadButton),
+    // Demi-axe d'un stick ou d'une gâchette analogique
+    GamepadAxis {
+        axis: GamepadAxis,
+        direction: AxisDirection,
+    },
+    // Liaison active seulement si la touche de modification est enfoncée
+    Modified {
+        modifier: KeyModifier,
+        binding: Box<InputBinding>,
+    },
+}
+
+impl InputBinding {
+    pub fn with(self, modifier: KeyModifier) -> Self {
+        InputBinding::Modified {
+            modifier,
+            binding: Box::new(self),
+        }
+    }
+}
+
+/// Touche de modification, côté gauche ou droit
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum KeyModifier {
+    Shift,
+    Control,
+    Alt,
+    Super,
+}
+
+impl KeyModifier {
+    pub fn keys(self) -> [KeyCode; 2] {
+        match self {
+            KeyModifier::Shift => [KeyCode::ShiftLeft, KeyCode::ShiftRight],
+            KeyModifier::Control => [KeyCode::ControlLeft, KeyCode::ControlRight],
+            KeyModifier::Alt => [KeyCode::AltLeft, KeyCode::AltRight],
+            KeyModifier::Super => [KeyCode::SuperLeft, KeyCode::SuperRight],
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum AxisDirection {
+    Positive,
+    Negative,
+}
+
+impl AxisDirection {
+    pub fn sign(self) -> f32 {
+        match self {
+            AxisDirection::Positive => 1.0,
+            AxisDirection::Negative => -1.0,
+        }
+    }
+}
+
+/// Jeu de touches de départ, complété par les liaisons du fichier
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum KeyboardLayout {
+    #[default]
+    Qwerty,
+    // Lettres par caractère : ZQSD pour se déplacer
+    Azerty,
+}
+
+// Contenu du fichier de configuration (RON). Exemple :
+// (
+//     layout: Azerty,
+//     bindings: {
+//         ZoomIn: [Key(PageUp), Gamepad(RightTrigger)],
+//         DragPan: [Mouse(Middle)],
+//         Undo: [Modified(modifier: Control, binding: Char("w"))],
+//     },
+// )
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct InputConfig {
+    #[serde(default)]
+    layout: KeyboardLayout,
+    // Remplace toutes les liaisons des actions listées
+    #[serde(default)]
+    bindings: HashMap<InputAction, Vec<InputBinding>>,
+}
+
+/// Liaisons actions → entrées clavier, souris et manette
+#[derive(Resource, Clone, Debug)]
+pub struct InputMap {
+    pub bindings: HashMap<InputAction, Vec<InputBinding>>,
+    // En dessous, un axe analogique est considéré au repos
+    pub dead_zone: f32,
+}
+
+impl Default for InputMap {
+    fn default() -> Self {
+        Self::for_layout(KeyboardLayout::default())
+    }
+}
+
+impl InputMap {
+    pub fn for_layout(layout: KeyboardLayout) -> Self {
+        use InputAction::*;
+        use InputBinding::{Gamepad, Key, Mouse};
+
+        // Lettres : position physique en QWERTY, caractère en AZERTY
+        let letter = |code: KeyCode, character: &str| match layout {
+            KeyboardLayout::Qwerty => Key(code),
+            KeyboardLayout::Azerty => InputBinding::Char(character.to_string()),
+        };
+        let stick = |axis: GamepadAxis, direction: AxisDirection| InputBinding::GamepadAxis {
+            axis,
+            direction,
+        };
+
+        let mut bindings = HashMap::from([
+            (
+                PanUp,
+                vec![
+                    letter(KeyCode::KeyW, "z"),
+                    Key(KeyCode::ArrowUp),
+                    stick(GamepadAxis::LeftStickY, AxisDirection::Positive),
+                ],
+            ),
+            (
+                PanDown,
+                vec![
+                    letter(KeyCode::KeyS, "s"),
+                    Key(KeyCode::ArrowDown),
+                    stick(GamepadAxis::LeftStickY, AxisDirection::Negative),
+                ],
+            ),
+            (
+                PanLeft,
+                vec![
+                    letter(KeyCode::KeyA, "q"),
+                    Key(KeyCode::ArrowLeft),
+                    stick(GamepadAxis::LeftStickX, AxisDirection::Negative),
+                ],
+            ),
+            (
+                PanRight,
+                vec![
+                    letter(KeyCode::KeyD, "d"),
+                    Key(KeyCode::ArrowRight),
+                    stick(GamepadAxis::LeftStickX, AxisDirection::Positive),
+                ],
+            ),
+            (
+                ZoomIn,
+                vec![Key(KeyCode::NumpadAdd), Gamepad(GamepadButton::RightTrigger)],
+            ),
+            (
+                ZoomOut,
+                vec![Key(KeyCode::NumpadSubtract), Gamepad(GamepadButton::LeftTrigger)],
+            ),
+            // Le clic droit reste à DespawnChunk
+            (DragPan, vec![Mouse(MouseButton::Middle)]),
+            (Select, vec![Mouse(MouseButton::Left), Gamepad(GamepadButton::South)]),
+            // Lue par le picking sur les tuiles : souris uniquement
+            (DespawnChunk, vec![Mouse(MouseButton::Right)]),
+            (
+                ToggleEditor,
+                vec![letter(KeyCode::KeyE, "e"), Gamepad(GamepadButton::Select)],
+            ),
+            (BrushTool, vec![letter(KeyCode::KeyB, "b")]),
+            (LineTool, vec![letter(KeyCode::KeyL, "l")]),
+            (FillTool, vec![letter(KeyCode::KeyF, "f")]),
+            (
+                BrushSmaller,
+                vec![Key(KeyCode::BracketLeft), Gamepad(GamepadButton::DPadLeft)],
+            ),
+            (
+                BrushLarger,
+                vec![Key(KeyCode::BracketRight), Gamepad(GamepadButton::DPadRight)],
+            ),
+            (Cancel, vec![Key(KeyCode::Escape)]),
+            (ToggleMinimap, vec![letter(KeyCode::KeyM, "m")]),
+            (Undo, vec![letter(KeyCode::KeyZ, "z").with(KeyModifier::Control)]),
+            (
+                Redo,
+                vec![
+                    letter(KeyCode::KeyY, "y").with(KeyModifier::Control),
+                    letter(KeyCode::KeyZ, "z")
+                        .with(KeyModifier::Shift)
+                        .with(KeyModifier::Control),
+                ],
+            ),
+            (EditSession, vec![Key(KeyCode::F10)]),
+            (TogglePathDebug, vec![letter(KeyCode::KeyP, "p")]),
+            (QuickSave, vec![Key(KeyCode::F5)]),
+            (QuickLoad, vec![Key(KeyCode::F9)]),
+            (ExportSvg, vec![Key(KeyCode::F6)]),
+            (ExportMeshes, vec![Key(KeyCode::F7)]),
+            (ToggleChunkDebug, vec![Key(KeyCode::F1)]),
+            (ToggleHexDebug, vec![Key(KeyCode::F2)]),
+            (CyclePickingDebug, vec![Key(KeyCode::F3)]),
+            (ToggleCoordDebug, vec![Key(KeyCode::F4)]),
+            (ToggleContourDebug, vec![Key(KeyCode::F8)]),
+            (ToggleRawContours, vec![Key(KeyCode::F8).with(KeyModifier::Shift)]),
+            (ToggleMarchingGridDebug, vec![Key(KeyCode::F12)]),
+        ]);
+
+        // Chiffres de la rangée du haut : position physique quelle que soit
+        // la disposition (Maj est nécessaire en AZERTY pour le caractère)
+        let digits = [
+            KeyCode::Digit1,
+            KeyCode::Digit2,
+            KeyCode::Digit3,
+            KeyCode::Digit4,
+            KeyCode::Digit5,
+            KeyCode::Digit6,
+            KeyCode::Digit7,
+            KeyCode::Digit8,
+            KeyCode::Digit9,
+        ];
+        for (action, digit) in InputAction::TERRAINS.into_iter().zip(digits) {
+            bindings.insert(action, vec![Key(digit)]);
+        }
+        for (action, digit) in InputAction::BOOKMARKS.into_iter().zip(digits) {
+            bindings.insert(action, vec![Key(digit)]);
+        }
+
+        Self {
+            bindings,
+            dead_zone: 0.15,
+        }
+    }
+
+    /// Charge le fichier de configuration : disposition de départ, puis
+    /// liaisons remplacées action par action
+    pub fn load(path: &Path) -> io::Result<Self> {
+        let text = fs::read_to_string(path)?;
+        let config: InputConfig = ron::from_str(&text)
+            .map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))?;
+
+        let mut map = Self::for_layout(config.layout);
+        map.bindings.extend(config.bindings);
+        Ok(map)
+    }
+
+    pub fn bindings(&self, action: InputAction) -> &[InputBinding] {
+        self.bindings.get(&action).map_or(&[], Vec::as_slice)
+    }
+
+    pub fn bind(&mut self, action: InputAction, bindings: Vec<InputBinding>) {
+        self.bindings.insert(action, bindings);
+    }
+
+    /// Vrai si le bouton du pointeur (bevy_picking) est lié à l'action.
+    /// Les liaisons clavier et manette de l'action sont ignorées.
+    pub fn binds_pointer(&self, action: InputAction, button: PointerButton) -> bool {
+        let mouse_button = match button {
+            PointerButton::Primary => MouseButton::Left,
+            PointerButton::Secondary => MouseButton::Right,
+            PointerButton::Middle => MouseButton::Middle,
+        };
+        self.bindings(action).contains(&InputBinding::Mouse(mouse_button))
+    }
+}
@@ -0,0 +1,13 @@
+// =============================================================================
+// INPUT MODULE
+// =============================================================================
+
+pub mod action_input;
+pub mod input_action;
+pub mod input_map;
+pub mod plugin;
+
+pub use action_input::ActionInput;
+pub use input_action::InputAction;
+pub use input_map::{AxisDirection, InputBinding, InputMap, KeyModifier, KeyboardLayout};
+pub use plugin::InputMapPlugin;
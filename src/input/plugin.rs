@@ -0,0 +1,29 @@
+use bevy::prelude::*;
+use std::io;
+use std::path::Path;
+
+use super::InputMap;
+
+const INPUT_CONFIG_PATH: &str = "config/input.ron";
+
+/// Charge les liaisons de `config/input.ron`, ou les liaisons QWERTY par défaut
+pub struct InputMapPlugin;
+
+impl Plugin for InputMapPlugin {
+    fn build(&self, app: &mut App) {
+        let path = Path::new(INPUT_CONFIG_PATH);
+        let input_map = match InputMap::load(path) {
+            Ok(input_map) => {
+                info!("✓ Contrôles chargés ({})", path.display());
+                input_map
+            }
+            Err(err) if err.kind() == io::ErrorKind::NotFound => InputMap::default(),
+            Err(err) => {
+                error!("Configuration des contrôles invalide ({}) : {}", path.display(), err);
+                InputMap::default()
+            }
+        };
+
+        app.insert_resource(input_map);
+    }
+}
@@ -0,0 +1,8 @@
+pub mod camera;
+pub mod debug;
+pub mod editor;
+pub mod hex;
+pub mod input;
+pub mod shared;
+pub mod state;
+pub mod ui;
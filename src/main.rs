@@ -4,16 +4,10 @@ use bevy::prelude::*;
 use bevy::window::PresentMode;
 // mod hex::rendering;
 use bevy::dev_tools::picking_debug::{DebugPickingMode, DebugPickingPlugin};
-use bevy::diagnostic::{
-    EntityCountDiagnosticsPlugin, FrameTimeDiagnosticsPlugin, LogDiagnosticsPlugin,
-};
+use bevy::diagnostic::{EntityCountDiagnosticsPlugin, FrameTimeDiagnosticsPlugin};
 use bevy_inspector_egui::bevy_egui::EguiPlugin;
 use bevy_inspector_egui::quick::WorldInspectorPlugin;
-mod camera;
-mod hex;
-mod shared;
-mod state;
-mod ui;
+use playground::{camera, debug, editor, hex, input, state, ui};
 
 fn main() {
     App::new()
@@ -36,7 +30,9 @@ fn main() {
             //     filter: "bevy_dev_tools=trace".into(),
             //     ..default()
             // }),
-            MeshPickingPlugin,
+            // Picking analytique des hexagones à la place du raycast sur chaque mesh
+            // MeshPickingPlugin,
+            hex::input::HexPickingPlugin,
             DebugPickingPlugin,
         ))
         .register_type::<Aabb>()
@@ -52,28 +48,23 @@ fn main() {
         .add_plugins(WorldInspectorPlugin::new())
         .insert_resource(DebugPickingMode::Normal)
         .add_plugins((
+            input::InputMapPlugin,
             camera::CameraPlugin,
             // state::StatePlugin,
+            state::save::SavePlugin,
+            editor::EditorPlugin,
             hex::rendering::HexRenderingPlugin,
+            hex::pathfinding::PathfindingPlugin,
             // hex::rendering::contour::OrganicContourPlugin,
             // hex::input::HexInputPlugin,
+            hex::input::HexPointerPlugin,
             ui::UiPlugin,
+            debug::DebugOverlayPlugin,
         ))
         .add_plugins((
             // LogDiagnosticsPlugin::default(),
             FrameTimeDiagnosticsPlugin::default(),
             EntityCountDiagnosticsPlugin::default(),
         ))
-        // .add_systems(
-        //     PreUpdate,
-        //     (|mut mode: ResMut<DebugPickingMode>| {
-        //         *mode = match *mode {
-        //             DebugPickingMode::Disabled => DebugPickingMode::Normal,
-        //             DebugPickingMode::Normal => DebugPickingMode::Noisy,
-        //             DebugPickingMode::Noisy => DebugPickingMode::Disabled,
-        //         }
-        //     })
-        //     .distributive_run_if(bevy::input::common_conditions::input_just_pressed(KeyCode::F3))
-        // )
         .run();
 }
@@ -1,7 +1,9 @@
+use serde::{Deserialize, Serialize};
+
 use super::TerrainType;
 use crate::hex::components::HexCoord;
 
-#[derive(Clone)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct CellData {
     pub coord: HexCoord,
     pub terrain_type: TerrainType,
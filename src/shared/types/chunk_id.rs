@@ -1,9 +1,10 @@
 use bevy::prelude::*;
-use hexx::Hex;
+use hexx::shapes;
+use serde::{Deserialize, Serialize};
 
 use crate::hex::{HexConfig, HexCoord};
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct ChunkId {
     pub coord: HexCoord,
     pub size: u8,
@@ -76,6 +77,18 @@ impl ChunkId {
             .collect()
     }
 
+    /// Hexagones couverts par le chunk
+    pub fn hex_coords(&self) -> Vec<HexCoord> {
+        let center = self.coord.to_hex().to_higher_res(self.size as u32);
+        shapes::Hexagon {
+            center,
+            radius: self.size as u32,
+        }
+        .coords()
+        .map(HexCoord::from_hex)
+        .collect()
+    }
+
     #[inline]
     pub fn from_position(position: Vec2, hex_config: &HexConfig) -> Self {
         let hex_coord = HexCoord::from_hex(hex_config.layout.world_pos_to_hex(position));
@@ -1,10 +1,47 @@
 use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
 
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum TerrainType {
     DeepWater,    // Loin de la côte
     ShallowWater, // Proche de la côte
     Beach,        // Frontière côté mer
     Cliff,        // Frontière côté terre (si élévation)
     Land,         // Terre ferme
-}
\ No newline at end of file
+}
+
+impl TerrainType {
+    pub const ALL: [TerrainType; 5] = [
+        TerrainType::DeepWater,
+        TerrainType::ShallowWater,
+        TerrainType::Beach,
+        TerrainType::Cliff,
+        TerrainType::Land,
+    ];
+
+    /// Terre ferme ou falaise (la plage est côté mer)
+    pub fn is_land(&self) -> bool {
+        matches!(self, TerrainType::Cliff | TerrainType::Land)
+    }
+
+    /// Nom court, utilisé comme clé de matériau
+    pub fn name(&self) -> &'static str {
+        match self {
+            TerrainType::DeepWater => "deep_water",
+            TerrainType::ShallowWater => "shallow_water",
+            TerrainType::Beach => "beach",
+            TerrainType::Cliff => "cliff",
+            TerrainType::Land => "land",
+        }
+    }
+
+    pub fn color(&self) -> Color {
+        match self {
+            TerrainType::DeepWater => Color::srgb(0.1, 0.25, 0.55),
+            TerrainType::ShallowWater => Color::srgb(0.35, 0.6, 0.8),
+            TerrainType::Beach => Color::srgb(0.93, 0.85, 0.6),
+            TerrainType::Cliff => Color::srgb(0.5, 0.42, 0.35),
+            TerrainType::Land => Color::srgb(0.8, 0.6, 0.4),
+        }
+    }
+}
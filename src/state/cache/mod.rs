@@ -1,3 +1,5 @@
+mod terrain_cache;
 mod world_cache;
 
-pub use world_cache::WorldCache;
\ No newline at end of file
+pub use terrain_cache::TerrainCache;
+pub use world_cache::WorldCache;
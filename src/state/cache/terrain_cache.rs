@@ -0,0 +1,148 @@
+use hexx::HexOrientation;
+use serde::{Deserialize, Serialize};
+use std::fs::{self, File};
+use std::io::{self, BufReader, BufWriter, Read, Write};
+use std::path::Path;
+
+use crate::hex::{HexConfig, HexMap};
+use crate::hex::rendering::contour::terrain::TerrainMeshes;
+use crate::hex::rendering::contour::{ContourConfig, TerrainContours};
+use crate::shared::types::CellData;
+
+const MAGIC: &[u8; 8] = b"TERRAIN\0";
+// À incrémenter dès que le format sérialisé ou le pipeline de génération change
+const FORMAT_VERSION: u32 = 3;
+
+/// Terrain généré, sérialisé sur disque pour éviter de le régénérer au démarrage
+#[derive(Serialize, Deserialize)]
+pub struct TerrainCache {
+    // Hash des entrées (image, HexConfig, ContourConfig, rayon)
+    pub key: u64,
+    pub cells: HexMap<CellData>,
+    pub contours: TerrainContours,
+    pub meshes: TerrainMeshes,
+}
+
+impl TerrainCache {
+    /// Clé stable des entrées de la génération. Toute modification de l'image
+    /// ou des configurations produit une clé différente.
+    pub fn key(
+        image_bytes: &[u8],
+        hex_config: &HexConfig,
+        contour_config: &ContourConfig,
+        hex_radius: u32,
+    ) -> u64 {
+        let mut hasher = Fnv1a::default();
+        hasher.write(&FORMAT_VERSION.to_le_bytes());
+        hasher.write(image_bytes);
+
+        // Paramètres de la HexConfig (comme HexConfigData), dont découlent les layouts
+        hasher.write(&hex_config.hex_radius.to_le_bytes());
+        hasher.write(&[match hex_config.orientation {
+            HexOrientation::Flat => 0,
+            HexOrientation::Pointy => 1,
+        }]);
+        hasher.write(&hex_config.ratio.x.to_le_bytes());
+        hasher.write(&hex_config.ratio.y.to_le_bytes());
+        hasher.write(&[hex_config.chunk_size]);
+
+        let contour_bytes =
+            bincode::serialize(contour_config).expect("ContourConfig doit être sérialisable");
+        hasher.write(&contour_bytes);
+        hasher.write(&hex_radius.to_le_bytes());
+
+        hasher.finish()
+    }
+
+    /// Charge le cache s'il existe, est lisible et correspond à la clé
+    pub fn load(path: &Path, key: u64) -> Option<Self> {
+        let mut reader = BufReader::new(File::open(path).ok()?);
+
+        let mut magic = [0u8; 8];
+        let mut version = [0u8; 4];
+        let mut stored_key = [0u8; 8];
+        reader.read_exact(&mut magic).ok()?;
+        reader.read_exact(&mut version).ok()?;
+        reader.read_exact(&mut stored_key).ok()?;
+
+        // En-tête vérifié avant de désérialiser le contenu complet
+        if &magic != MAGIC
+            || u32::from_le_bytes(version) != FORMAT_VERSION
+            || u64::from_le_bytes(stored_key) != key
+        {
+            return None;
+        }
+
+        let cache: TerrainCache = bincode::deserialize_from(reader).ok()?;
+        (cache.key == key).then_some(cache)
+    }
+
+    /// Écrit le cache (dossiers parents créés au besoin)
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+
+        let mut writer = BufWriter::new(File::create(path)?);
+        writer.write_all(MAGIC)?;
+        writer.write_all(&FORMAT_VERSION.to_le_bytes())?;
+        writer.write_all(&self.key.to_le_bytes())?;
+        bincode::serialize_into(&mut writer, self).map_err(io::Error::other)?;
+        writer.flush()
+    }
+}
+
+// FNV-1a 64 bits : stable entre compilations et versions de Rust,
+// contrairement au DefaultHasher de la std
+struct Fnv1a(u64);
+
+impl Default for Fnv1a {
+    fn default() -> Self {
+        Self(0xcbf2_9ce4_8422_2325)
+    }
+}
+
+impl Fnv1a {
+    fn write(&mut self, bytes: &[u8]) {
+        for byte in bytes {
+            self.0 ^= *byte as u64;
+            self.0 = self.0.wrapping_mul(0x0000_0100_0000_01b3);
+        }
+    }
+
+    fn finish(&self) -> u64 {
+        self.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::math::Vec2;
+
+    fn key(hex_config: &HexConfig) -> u64 {
+        TerrainCache::key(b"image", hex_config, &ContourConfig::default(), 32)
+    }
+
+    #[test]
+    fn key_follows_hex_config_fields() {
+        let config = HexConfig::default();
+        assert_eq!(key(&config), key(&HexConfig::default()));
+
+        let (radius, orientation, ratio, chunk_size) = (
+            config.hex_radius,
+            config.orientation,
+            config.ratio,
+            config.chunk_size,
+        );
+        let variants = [
+            HexConfig::new(32.0, orientation, ratio, chunk_size),
+            HexConfig::new(radius, HexOrientation::Pointy, ratio, chunk_size),
+            HexConfig::new(radius, orientation, Vec2::new(1.0, 0.5), chunk_size),
+            HexConfig::new(radius, orientation, ratio, 4),
+        ];
+        for variant in &variants {
+            assert_ne!(key(&config), key(variant));
+        }
+    }
+}
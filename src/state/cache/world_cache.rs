@@ -35,7 +35,7 @@ impl WorldCache {
     pub fn unload_distant(&mut self, center: ChunkId, max_distance: u32) -> Vec<ChunkId> {
 
         self.chunks.retain(|chunk_id| {
-            let keep = chunk_id.distance(&center) as u32 <= max_distance + 1;
+            let keep = chunk_id.distance(&center) <= max_distance + 1;
 
             if !keep {
                 self.unload_chunks_request.insert(*chunk_id);
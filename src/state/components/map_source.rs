@@ -0,0 +1,19 @@
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// Binary map à partir de laquelle le terrain est généré
+#[derive(Resource, Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MapSource {
+    pub image_path: String,
+    // Rayon de la grille hexagonale échantillonnée
+    pub hex_radius: u32,
+}
+
+impl Default for MapSource {
+    fn default() -> Self {
+        Self {
+            image_path: "assets/maps/Gaulyia_binarymap.png".to_string(),
+            hex_radius: 100,
+        }
+    }
+}
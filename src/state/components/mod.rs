@@ -1,3 +1,7 @@
+mod map_source;
 mod streaming_config;
+mod terrain_overrides;
 
-pub use streaming_config::StreamingConfig;
\ No newline at end of file
+pub use map_source::MapSource;
+pub use streaming_config::StreamingConfig;
+pub use terrain_overrides::TerrainOverrides;
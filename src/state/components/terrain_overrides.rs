@@ -0,0 +1,25 @@
+use bevy::prelude::*;
+use std::collections::HashMap;
+
+use crate::hex::HexCoord;
+use crate::shared::types::TerrainType;
+
+/// Terrain imposé manuellement sur certaines cellules (édition de la carte)
+#[derive(Resource, Clone, Default, Debug)]
+pub struct TerrainOverrides {
+    pub cells: HashMap<HexCoord, TerrainType>,
+}
+
+impl TerrainOverrides {
+    pub fn get(&self, coord: &HexCoord) -> Option<TerrainType> {
+        self.cells.get(coord).copied()
+    }
+
+    pub fn set(&mut self, coord: HexCoord, terrain_type: TerrainType) -> Option<TerrainType> {
+        self.cells.insert(coord, terrain_type)
+    }
+
+    pub fn clear(&mut self, coord: &HexCoord) -> Option<TerrainType> {
+        self.cells.remove(coord)
+    }
+}
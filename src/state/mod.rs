@@ -2,5 +2,6 @@ pub mod cache;
 pub mod components;
 pub mod streaming;
 pub mod plugin;
+pub mod save;
 
 pub use plugin::StatePlugin;
\ No newline at end of file
@@ -0,0 +1,7 @@
+mod plugin;
+pub mod systems;
+mod world_save;
+
+pub use plugin::SavePlugin;
+pub use systems::WorldSavePath;
+pub use world_save::{HexConfigData, WORLD_SAVE_VERSION, WorldSave};
@@ -0,0 +1,20 @@
+use bevy::prelude::*;
+
+use crate::state::cache::WorldCache;
+use crate::state::components::MapSource;
+
+use super::systems;
+
+pub struct SavePlugin;
+
+impl Plugin for SavePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<systems::WorldSavePath>()
+            .init_resource::<MapSource>()
+            .init_resource::<WorldCache>()
+            .add_systems(
+                Update,
+                (systems::save_world_on_key, systems::load_world_on_key),
+            );
+    }
+}
@@ -0,0 +1,110 @@
+use bevy::prelude::*;
+use std::collections::HashSet;
+use std::path::PathBuf;
+
+use crate::hex::HexConfig;
+use crate::hex::rendering::atlas::ColorTintMaterials;
+use crate::hex::rendering::components::HexTile;
+use crate::hex::rendering::systems::spawn_chunk;
+use crate::input::{ActionInput, InputAction};
+use crate::state::cache::WorldCache;
+use crate::state::components::{MapSource, TerrainOverrides};
+
+use super::WorldSave;
+
+/// Emplacement de la sauvegarde rapide
+#[derive(Resource, Clone, Debug)]
+pub struct WorldSavePath(pub PathBuf);
+
+impl Default for WorldSavePath {
+    fn default() -> Self {
+        Self(PathBuf::from("saves/world.sav"))
+    }
+}
+
+/// F5 (par défaut) : sauvegarde rapide du monde
+pub fn save_world_on_key(
+    input: ActionInput,
+    save_path: Res<WorldSavePath>,
+    hex_config: Res<HexConfig>,
+    map_source: Res<MapSource>,
+    overrides: Option<Res<TerrainOverrides>>,
+    world_cache: Res<WorldCache>,
+) {
+    if !input.just_pressed(InputAction::QuickSave) {
+        return;
+    }
+
+    // Sans EditorPlugin, aucune cellule n'est modifiée
+    let no_overrides = TerrainOverrides::default();
+    let overrides = overrides.as_deref().unwrap_or(&no_overrides);
+    let save = WorldSave::capture(&hex_config, &map_source, overrides, &world_cache);
+    match save.save(&save_path.0) {
+        Ok(()) => info!(
+            "💾 Monde sauvegardé dans {} ({} cellules modifiées, {} chunks)",
+            save_path.0.display(),
+            save.terrain_overrides.len(),
+            save.chunks.len()
+        ),
+        Err(err) => error!("Échec de la sauvegarde {} : {}", save_path.0.display(), err),
+    }
+}
+
+/// F9 (par défaut) : chargement rapide du monde. Le terrain est régénéré par
+/// OrganicContourPlugin si la HexConfig ou la carte diffèrent.
+pub fn load_world_on_key(
+    mut commands: Commands,
+    input: ActionInput,
+    save_path: Res<WorldSavePath>,
+    mut world_cache: ResMut<WorldCache>,
+    color_tint_materials: Option<Res<ColorTintMaterials>>,
+    tiles: Query<Entity, With<HexTile>>,
+) {
+    if !input.just_pressed(InputAction::QuickLoad) {
+        return;
+    }
+
+    let save = match WorldSave::load(&save_path.0) {
+        Ok(save) => save,
+        Err(err) => {
+            error!("Échec du chargement {} : {}", save_path.0.display(), err);
+            return;
+        }
+    };
+
+    let hex_config = save.hex_config.to_config();
+
+    // Les tuiles des chunks courants sont remplacées par celles des chunks
+    // sauvegardés, placées selon la HexConfig chargée
+    for entity in &tiles {
+        commands.entity(entity).despawn();
+    }
+    match color_tint_materials {
+        Some(color_tint_materials) => {
+            for chunk_id in &save.chunks {
+                spawn_chunk(
+                    &mut commands,
+                    chunk_id,
+                    &hex_config,
+                    &color_tint_materials,
+                    HashSet::new(),
+                );
+            }
+        }
+        None => warn!("Matériaux des tuiles non chargés, chunks non affichés"),
+    }
+
+    commands.insert_resource(hex_config);
+    commands.insert_resource(save.map_source.clone());
+    commands.insert_resource(save.overrides());
+
+    world_cache.chunks = save.chunks.iter().copied().collect();
+    world_cache.requested_chunks.clear();
+
+    info!(
+        "📂 Monde chargé depuis {} ({} cellules modifiées, {} chunks)",
+        save_path.0.display(),
+        save.terrain_overrides.len(),
+        save.chunks.len()
+    );
+}
@@ -0,0 +1,128 @@
+use bevy::prelude::*;
+use hexx::HexOrientation;
+use serde::{Deserialize, Serialize};
+use std::fs::{self, File};
+use std::io::{self, BufReader, BufWriter, Read, Write};
+use std::path::Path;
+
+use crate::hex::{HexConfig, HexCoord};
+use crate::shared::types::{ChunkId, TerrainType};
+use crate::state::cache::WorldCache;
+use crate::state::components::{MapSource, TerrainOverrides};
+
+const MAGIC: &[u8; 8] = b"HEXWORLD";
+// À incrémenter à chaque changement de WorldSave, en gardant la lecture
+// des versions précédentes dans `WorldSave::load`
+pub const WORLD_SAVE_VERSION: u32 = 1;
+
+/// Paramètres de HexConfig nécessaires pour reconstruire les layouts
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct HexConfigData {
+    pub radius: f32,
+    pub orientation: HexOrientation,
+    pub ratio: Vec2,
+    pub chunk_size: u8,
+}
+
+impl From<&HexConfig> for HexConfigData {
+    fn from(config: &HexConfig) -> Self {
+        Self {
+            radius: config.hex_radius,
+            orientation: config.orientation,
+            ratio: config.ratio,
+            chunk_size: config.chunk_size,
+        }
+    }
+}
+
+impl HexConfigData {
+    pub fn to_config(&self) -> HexConfig {
+        HexConfig::new(self.radius, self.orientation, self.ratio, self.chunk_size)
+    }
+}
+
+/// État du monde sauvegardé : le terrain lui-même est régénéré (ou relu depuis
+/// le cache) à partir de la source, seules les modifications sont stockées
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct WorldSave {
+    pub hex_config: HexConfigData,
+    pub map_source: MapSource,
+    pub terrain_overrides: Vec<(HexCoord, TerrainType)>,
+    pub chunks: Vec<ChunkId>,
+}
+
+impl WorldSave {
+    pub fn capture(
+        hex_config: &HexConfig,
+        map_source: &MapSource,
+        overrides: &TerrainOverrides,
+        world_cache: &WorldCache,
+    ) -> Self {
+        // Tri pour qu'un même monde produise toujours le même fichier
+        let mut terrain_overrides: Vec<_> = overrides
+            .cells
+            .iter()
+            .map(|(coord, terrain)| (*coord, *terrain))
+            .collect();
+        terrain_overrides.sort_by_key(|(coord, _)| (coord.q, coord.r));
+
+        let mut chunks: Vec<_> = world_cache.chunks.iter().copied().collect();
+        chunks.sort_by_key(|chunk| (chunk.coord.q, chunk.coord.r));
+
+        Self {
+            hex_config: HexConfigData::from(hex_config),
+            map_source: map_source.clone(),
+            terrain_overrides,
+            chunks,
+        }
+    }
+
+    pub fn overrides(&self) -> TerrainOverrides {
+        TerrainOverrides {
+            cells: self.terrain_overrides.iter().copied().collect(),
+        }
+    }
+
+    /// Écrit la sauvegarde (dossiers parents créés au besoin)
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+
+        let mut writer = BufWriter::new(File::create(path)?);
+        writer.write_all(MAGIC)?;
+        writer.write_all(&WORLD_SAVE_VERSION.to_le_bytes())?;
+        bincode::serialize_into(&mut writer, self).map_err(io::Error::other)?;
+        writer.flush()
+    }
+
+    /// Relit une sauvegarde. Un fichier d'une version plus récente que celle
+    /// du jeu est refusé plutôt que lu de travers.
+    pub fn load(path: &Path) -> io::Result<Self> {
+        let mut reader = BufReader::new(File::open(path)?);
+
+        let mut magic = [0u8; 8];
+        let mut version = [0u8; 4];
+        reader.read_exact(&mut magic)?;
+        reader.read_exact(&mut version)?;
+
+        if &magic != MAGIC {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "fichier de sauvegarde invalide",
+            ));
+        }
+
+        match u32::from_le_bytes(version) {
+            WORLD_SAVE_VERSION => bincode::deserialize_from(reader)
+                .map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err)),
+            version => Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!(
+                    "version de sauvegarde {} non supportée (attendue : {})",
+                    version, WORLD_SAVE_VERSION
+                ),
+            )),
+        }
+    }
+}
@@ -1,5 +1,4 @@
 use bevy::prelude::*;
-use hexx::*;
 
 use crate::camera::MainCamera;
 use crate::hex::HexConfig;
@@ -0,0 +1,298 @@
+// =============================================================================
+// Terrain CLI - génération headless (sans fenêtre, sans GPU)
+// =============================================================================
+//
+// Exemple :
+//   cargo run --bin terrain_cli -- --map assets/maps/Gaulyia_binarymap.png \
+//       --radius 100 --seed 7 --smoothing chaikin:3 --output exports/seed_7
+
+use std::fmt::Write as _;
+use std::path::PathBuf;
+use std::process::ExitCode;
+use std::time::Instant;
+
+use playground::hex::HexConfig;
+use playground::hex::rendering::config::GAME_HEX_RADIUS;
+use playground::hex::rendering::contour::export::{
+    MeshExportFormat, SvgExportOptions, contour_layers, export_meshes, export_svg,
+};
+use playground::hex::rendering::contour::systems::{OrganicContourSystem, organic_contour_config};
+use playground::hex::rendering::contour::{ContourConfig, ContourSmoothing};
+use playground::shared::types::TerrainType;
+
+const USAGE: &str = "\
+Usage: terrain_cli [OPTIONS]
+
+Entrées :
+  --map <PATH>               Binary map (défaut: assets/maps/Gaulyia_binarymap.png)
+  --radius <N>               Rayon de la grille en hexagones (défaut: 100)
+  --hex-size <F>             Rayon d'un hexagone en unités monde (défaut: 48)
+
+ContourConfig :
+  --seed <N>                 Graine du bruit
+  --pixels-per-hex <F>
+  --threshold <F>            Seuil terre/mer
+  --noise-amplitude <F>
+  --noise-frequency <F>      Cycles par hexagone
+  --noise-octaves <N>
+  --noise-lacunarity <F>
+  --noise-persistence <F>
+  --warp-amplitude <F>       En hexagones
+  --warp-frequency <F>
+  --spline-tension <F>
+  --smoothing <S>            none | chaikin:N | catmull-rom:N | adaptive:N
+  --iso-grid-size <N>
+  --depth-levels <L,..>      Ex: -2,-5,-10
+  --elevation-levels <L,..>  Ex: 3,6,10
+
+Sorties :
+  --output <DIR>             Dossier des exports (défaut: exports)
+  --raw                      Exporter les contours SVG non lissés
+  --no-export                N'écrire que les statistiques
+  -h, --help
+";
+
+struct CliArgs {
+    map: PathBuf,
+    radius: u32,
+    hex_size: f32,
+    output: PathBuf,
+    raw: bool,
+    export: bool,
+    config: ContourConfig,
+}
+
+fn main() -> ExitCode {
+    let args = match parse_args(std::env::args().skip(1)) {
+        Ok(Some(args)) => args,
+        Ok(None) => {
+            print!("{USAGE}");
+            return ExitCode::SUCCESS;
+        }
+        Err(err) => {
+            eprintln!("Erreur: {err}\n\n{USAGE}");
+            return ExitCode::from(2);
+        }
+    };
+
+    match run(args) {
+        Ok(true) => ExitCode::SUCCESS,
+        Ok(false) => ExitCode::FAILURE,
+        Err(err) => {
+            eprintln!("Erreur: {err}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+// Renvoie Ok(false) si la validation des meshes échoue
+fn run(args: CliArgs) -> Result<bool, String> {
+    let total_start = Instant::now();
+    let mut stats = String::new();
+
+    let start = Instant::now();
+    let binary_map = image::open(&args.map)
+        .map_err(|err| format!("Impossible de charger {}: {err}", args.map.display()))?;
+    let _ = writeln!(
+        stats,
+        "map: {} ({}x{}) chargée en {:.2?}",
+        args.map.display(),
+        binary_map.width(),
+        binary_map.height(),
+        start.elapsed()
+    );
+
+    let hex_config = HexConfig::game(args.hex_size);
+    let mut system = OrganicContourSystem::new(binary_map, hex_config.layout, args.config);
+
+    // Échantillonnage et classification
+    let start = Instant::now();
+    system.initialize_hex_grid(args.radius);
+    let _ = writeln!(
+        stats,
+        "cellules: {} (rayon {}) en {:.2?}",
+        system.hex_cells().len(),
+        args.radius,
+        start.elapsed()
+    );
+    for terrain_type in [
+        TerrainType::DeepWater,
+        TerrainType::ShallowWater,
+        TerrainType::Beach,
+        TerrainType::Cliff,
+        TerrainType::Land,
+    ] {
+        let count = system
+            .hex_cells()
+            .values()
+            .filter(|cell| cell.terrain_type == terrain_type)
+            .count();
+        let _ = writeln!(stats, "  {:?}: {}", terrain_type, count);
+    }
+
+    let regions = system.regions();
+    let _ = writeln!(
+        stats,
+        "régions: {} îles, {} lacs, {} au total",
+        regions.islands().count(),
+        regions.lakes().count(),
+        regions.regions().len()
+    );
+
+    // Contours et meshes
+    let start = Instant::now();
+    let (contours, meshes) = system.generate();
+    let _ = writeln!(stats, "génération contours + meshes en {:.2?}", start.elapsed());
+
+    let closed = contours.coastline.iter().filter(|c| c.is_closed).count();
+    let points: usize = contours.coastline.iter().map(|c| c.points.len()).sum();
+    let _ = writeln!(
+        stats,
+        "côtes: {} ({} fermées, {} ouvertes), {} points",
+        contours.coastline.len(),
+        closed,
+        contours.coastline.len() - closed,
+        points
+    );
+    let _ = writeln!(stats, "iso-contours: {}", contours.iso_contours.len());
+
+    // Validation des meshes (indices u32, aucun débordement sur les grandes cartes)
+    let mut valid = true;
+    for (name, data) in meshes.named() {
+        let check = data.validate();
+        valid &= check.is_ok();
+        let _ = writeln!(
+            stats,
+            "mesh {}: {} vertices, {} triangles{}",
+            name,
+            data.vertices.len(),
+            data.indices.len() / 3,
+            match check {
+                Ok(()) => String::new(),
+                Err(err) => format!(" [INVALIDE: {err}]"),
+            }
+        );
+    }
+
+    if args.export {
+        std::fs::create_dir_all(&args.output).map_err(|err| err.to_string())?;
+
+        let svg_options = SvgExportOptions {
+            output_path: args.output.join("coastline.svg"),
+            smoothed: !args.raw,
+            ..Default::default()
+        };
+        let layers = contour_layers(&contours, svg_options.smoothed);
+        export_svg(&svg_options.output_path, &layers, &svg_options)
+            .map_err(|err| err.to_string())?;
+        let _ = writeln!(stats, "export: {}", svg_options.output_path.display());
+
+        let written = export_meshes(
+            args.output.join("terrain"),
+            &meshes,
+            &[MeshExportFormat::Gltf, MeshExportFormat::Obj],
+        )
+        .map_err(|err| err.to_string())?;
+        for path in written {
+            let _ = writeln!(stats, "export: {}", path.display());
+        }
+    }
+
+    let _ = writeln!(stats, "total: {:.2?}", total_start.elapsed());
+    print!("{stats}");
+
+    if args.export {
+        let stats_path = args.output.join("stats.txt");
+        std::fs::write(&stats_path, &stats).map_err(|err| err.to_string())?;
+    }
+
+    if !valid {
+        eprintln!("Validation des meshes échouée");
+    }
+    Ok(valid)
+}
+
+// Renvoie Ok(None) si l'aide est demandée
+fn parse_args(mut args: impl Iterator<Item = String>) -> Result<Option<CliArgs>, String> {
+    let mut cli = CliArgs {
+        map: PathBuf::from("assets/maps/Gaulyia_binarymap.png"),
+        radius: 100,
+        hex_size: GAME_HEX_RADIUS,
+        output: PathBuf::from("exports"),
+        raw: false,
+        export: true,
+        config: organic_contour_config(),
+    };
+
+    while let Some(flag) = args.next() {
+        let mut value = || {
+            args.next()
+                .ok_or_else(|| format!("valeur manquante pour {flag}"))
+        };
+
+        let config = &mut cli.config;
+        match flag.as_str() {
+            "-h" | "--help" => return Ok(None),
+            "--map" => cli.map = PathBuf::from(value()?),
+            "--radius" => cli.radius = parse(&flag, &value()?)?,
+            "--hex-size" => cli.hex_size = parse(&flag, &value()?)?,
+            "--output" => cli.output = PathBuf::from(value()?),
+            "--raw" => cli.raw = true,
+            "--no-export" => cli.export = false,
+            "--seed" => config.seed = parse(&flag, &value()?)?,
+            "--pixels-per-hex" => config.pixels_per_hex = parse(&flag, &value()?)?,
+            "--threshold" => config.threshold = parse(&flag, &value()?)?,
+            "--noise-amplitude" => config.noise_amplitude = parse(&flag, &value()?)?,
+            "--noise-frequency" => config.noise_frequency = parse(&flag, &value()?)?,
+            "--noise-octaves" => config.noise_octaves = parse(&flag, &value()?)?,
+            "--noise-lacunarity" => config.noise_lacunarity = parse(&flag, &value()?)?,
+            "--noise-persistence" => config.noise_persistence = parse(&flag, &value()?)?,
+            "--warp-amplitude" => config.warp_amplitude = parse(&flag, &value()?)?,
+            "--warp-frequency" => config.warp_frequency = parse(&flag, &value()?)?,
+            "--spline-tension" => config.spline_tension = parse(&flag, &value()?)?,
+            "--smoothing" => config.smoothing = parse_smoothing(&value()?)?,
+            "--iso-grid-size" => config.iso_grid_size = parse(&flag, &value()?)?,
+            "--depth-levels" => config.depth_levels = parse_levels(&flag, &value()?)?,
+            "--elevation-levels" => config.elevation_levels = parse_levels(&flag, &value()?)?,
+            _ => return Err(format!("option inconnue: {flag}")),
+        }
+    }
+
+    Ok(Some(cli))
+}
+
+fn parse<T: std::str::FromStr>(flag: &str, value: &str) -> Result<T, String> {
+    value
+        .parse()
+        .map_err(|_| format!("valeur invalide pour {flag}: {value}"))
+}
+
+fn parse_levels(flag: &str, value: &str) -> Result<Vec<f32>, String> {
+    value
+        .split(',')
+        .filter(|part| !part.trim().is_empty())
+        .map(|part| parse(flag, part.trim()))
+        .collect()
+}
+
+fn parse_smoothing(value: &str) -> Result<ContourSmoothing, String> {
+    let (name, amount) = match value.split_once(':') {
+        Some((name, amount)) => (name, Some(parse::<u32>("--smoothing", amount)?)),
+        None => (value, None),
+    };
+
+    match name {
+        "none" => Ok(ContourSmoothing::None),
+        "chaikin" => Ok(ContourSmoothing::Chaikin {
+            iterations: amount.unwrap_or(3),
+        }),
+        "catmull-rom" => Ok(ContourSmoothing::CentripetalCatmullRom {
+            segments: amount.unwrap_or(5),
+        }),
+        "adaptive" => Ok(ContourSmoothing::Adaptive {
+            max_segments: amount.unwrap_or(10),
+            angle_per_segment: 0.1,
+        }),
+        _ => Err(format!("lissage inconnu: {value}")),
+    }
+}
@@ -1,4 +1,5 @@
 use bevy::prelude::*;
+use bevy_inspector_egui::bevy_egui;
 
 use crate::hex::rendering::contour::terrain::{TerrainGenerationProgress, GenerationStage};
 
@@ -0,0 +1,78 @@
+use bevy::prelude::*;
+use bevy_inspector_egui::bevy_egui::{self, egui};
+
+use crate::hex::input::HoveredHex;
+use crate::hex::rendering::config::HexConfig;
+use crate::hex::rendering::contour::terrain::TerrainCells;
+use crate::hex::HexRegions;
+use crate::shared::types::ChunkId;
+use crate::state::components::TerrainOverrides;
+
+/// Infobulle egui de la cellule survolée
+pub fn show_hex_tooltip(
+    hovered: Option<Res<HoveredHex>>,
+    hex_config: Option<Res<HexConfig>>,
+    cells: Option<Res<TerrainCells>>,
+    regions: Option<Res<HexRegions>>,
+    overrides: Option<Res<TerrainOverrides>>,
+    mut contexts: Query<&mut bevy_egui::EguiContext>,
+) {
+    let (Some(hovered), Some(hex_config)) = (hovered, hex_config) else {
+        return;
+    };
+    let Some(coord) = hovered.0 else {
+        return;
+    };
+    let Ok(mut ctx) = contexts.single_mut() else {
+        return;
+    };
+    let ctx = ctx.get_mut();
+
+    // Pas d'infobulle par-dessus les fenêtres egui
+    if ctx.is_pointer_over_area() {
+        return;
+    }
+    let Some(pointer) = ctx.input(|input| input.pointer.hover_pos()) else {
+        return;
+    };
+
+    let chunk = ChunkId::from_hex_coord(coord, hex_config.chunk_size);
+    let world_pos = hex_config.layout.hex_to_world_pos(coord.to_hex());
+    let cell = cells.as_ref().and_then(|cells| cells.get(&coord));
+    let painted = overrides.as_ref().and_then(|overrides| overrides.get(&coord));
+    let region = regions.as_ref().and_then(|regions| regions.region_of(&coord));
+
+    egui::Area::new(egui::Id::new("hex_tooltip"))
+        .order(egui::Order::Tooltip)
+        .fixed_pos(pointer + egui::vec2(16.0, 16.0))
+        .interactable(false)
+        .show(ctx, |ui| {
+            egui::Frame::popup(ui.style()).show(ui, |ui| {
+                ui.label(format!("Hex: ({}, {})", coord.q, coord.r));
+                ui.label(format!("Chunk: ({}, {})", chunk.q(), chunk.r()));
+                ui.label(format!("Position: ({:.1}, {:.1})", world_pos.x, world_pos.y));
+
+                let Some(cell) = cell else {
+                    ui.label("Hors de la carte");
+                    return;
+                };
+
+                ui.separator();
+                match painted {
+                    Some(terrain_type) => ui.label(format!("Terrain: {:?} (peint)", terrain_type)),
+                    None => ui.label(format!("Terrain: {:?}", cell.terrain_type)),
+                };
+                ui.label(format!("Échantillon: {:.3}", cell.sample_value));
+                ui.label(format!("Distance au bord: {:.1}", cell.distance_to_edge));
+                if cell.is_border {
+                    ui.label("Frontière terre/mer");
+                }
+                if let Some(region) = region {
+                    ui.label(format!(
+                        "Région: {:?} #{} ({} hex)",
+                        region.kind, region.id, region.area
+                    ));
+                }
+            });
+        });
+}
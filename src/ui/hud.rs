@@ -35,29 +35,25 @@ pub fn setup_hud(mut commands: Commands) {
         ))
         .with_children(|parent| {
             // Top-left: FPS
-            parent
-                .spawn((
-                    Text::new("FPS: -- (avg: --)"),
-                    TextFont {
-                        font_size: 14.0,
-                        ..default()
-                    },
-                    TextColor(Color::srgb(1.0, 0.0, 0.0)),
-                    Node {
-                        position_type: PositionType::Absolute,
-                        top: Val::Px(10.0),
-                        left: Val::Px(10.0),
-                        ..default()
-                    },
-                    FpsText,
-                    Pickable {
-                        should_block_lower: false,
-                        is_hoverable: false,
-                    },
-                ))
-                .observe(|over: On<Pointer<Over>>| {
-                    println!("oveerd");
-                });
+            parent.spawn((
+                Text::new("FPS: -- (avg: --)"),
+                TextFont {
+                    font_size: 14.0,
+                    ..default()
+                },
+                TextColor(Color::srgb(1.0, 0.0, 0.0)),
+                Node {
+                    position_type: PositionType::Absolute,
+                    top: Val::Px(10.0),
+                    left: Val::Px(10.0),
+                    ..default()
+                },
+                FpsText,
+                Pickable {
+                    should_block_lower: false,
+                    is_hoverable: false,
+                },
+            ));
 
             parent.spawn((
                 Text::new("Frame time: --ms"),
@@ -101,15 +97,19 @@ pub fn setup_hud(mut commands: Commands) {
         });
 }
 
-pub fn update_diagnostic_texts(
-    diagnostics: Res<DiagnosticsStore>,
-    mut query: Query<(
-        &mut Text,
-        Option<&FpsText>,
-        Option<&FrameTimeText>,
-        Option<&EntityCountText>,
-    )>,
-) {
+// Textes du HUD, chacun marqué par le diagnostic qu'il affiche
+type DiagnosticTexts<'w, 's> = Query<
+    'w,
+    's,
+    (
+        &'static mut Text,
+        Option<&'static FpsText>,
+        Option<&'static FrameTimeText>,
+        Option<&'static EntityCountText>,
+    ),
+>;
+
+pub fn update_diagnostic_texts(diagnostics: Res<DiagnosticsStore>, mut query: DiagnosticTexts) {
     let (fps_value, average_fps) =
         if let Some(fps) = diagnostics.get(&FrameTimeDiagnosticsPlugin::FPS) {
             if let Some(value) = fps.smoothed() {
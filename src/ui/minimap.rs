@@ -0,0 +1,184 @@
+use bevy::prelude::*;
+use bevy_inspector_egui::bevy_egui::{self, egui};
+use hexx::Hex;
+use std::collections::HashMap;
+
+use crate::camera::{CameraControl, MainCamera, world_view_rect};
+use crate::hex::rendering::config::HexConfig;
+use crate::hex::rendering::contour::systems::map_bounds;
+use crate::hex::rendering::contour::terrain::TerrainCells;
+use crate::input::{ActionInput, InputAction};
+use crate::state::cache::WorldCache;
+
+/// Vue d'ensemble de la carte en basse résolution
+#[derive(Resource, Clone, Debug)]
+pub struct Minimap {
+    pub visible: bool,
+    // Largeur du panneau en points egui
+    pub width: f32,
+    // Facteur de `Hex::to_lower_res` : une cellule de minimap couvre ce rayon
+    pub resolution: u32,
+    // Cellules basse résolution (centre monde, terre majoritaire)
+    cells: Vec<(Vec2, bool)>,
+    bounds: Rect,
+}
+
+impl Default for Minimap {
+    fn default() -> Self {
+        Self {
+            visible: true,
+            width: 220.0,
+            resolution: 3,
+            cells: Vec::new(),
+            bounds: Rect::default(),
+        }
+    }
+}
+
+impl Minimap {
+    // Position monde → position dans le panneau (y vers le bas)
+    fn to_panel(&self, panel: egui::Rect, world: Vec2) -> egui::Pos2 {
+        let t = (world - self.bounds.min) / self.bounds.size();
+        egui::pos2(
+            panel.min.x + t.x * panel.width(),
+            panel.max.y - t.y * panel.height(),
+        )
+    }
+
+    fn to_world(&self, panel: egui::Rect, pos: egui::Pos2) -> Vec2 {
+        let t = Vec2::new(
+            (pos.x - panel.min.x) / panel.width(),
+            (panel.max.y - pos.y) / panel.height(),
+        );
+        self.bounds.min + t.clamp(Vec2::ZERO, Vec2::ONE) * self.bounds.size()
+    }
+}
+
+/// M (par défaut) affiche/masque la minimap
+pub fn toggle_minimap(input: ActionInput, mut minimap: ResMut<Minimap>) {
+    if input.just_pressed(InputAction::ToggleMinimap) {
+        minimap.visible = !minimap.visible;
+    }
+}
+
+/// Regroupe les cellules du terrain par hexagone basse résolution (vote majoritaire)
+pub fn update_minimap(
+    mut minimap: ResMut<Minimap>,
+    hex_config: Res<HexConfig>,
+    cells: Option<Res<TerrainCells>>,
+) {
+    let Some(cells) = cells else {
+        return;
+    };
+    if !cells.is_changed() && !minimap.is_changed() {
+        return;
+    }
+
+    let resolution = minimap.resolution.max(1);
+    let mut votes: HashMap<Hex, (u32, u32)> = HashMap::new();
+    for (coord, cell) in cells.cells.iter() {
+        let vote = votes
+            .entry(coord.to_hex().to_lower_res(resolution))
+            .or_default();
+        if cell.terrain_type.is_land() {
+            vote.0 += 1;
+        } else {
+            vote.1 += 1;
+        }
+    }
+
+    let layout = &hex_config.layout;
+    let minimap = minimap.bypass_change_detection();
+    minimap.cells = votes
+        .into_iter()
+        .map(|(low_res, (land, water))| {
+            let center = layout.hex_to_world_pos(low_res.to_higher_res(resolution));
+            (center, land > water)
+        })
+        .collect();
+
+    let (min, max) = map_bounds(layout, cells.cells.coords());
+    minimap.bounds = Rect::from_corners(min, max);
+}
+
+/// Panneau egui : terre et eau, chunks chargés et rectangle de la vue.
+/// Un clic ou un glissé centre la caméra sur le point visé (focus animé,
+/// qui interrompt l'inertie et le suivi d'entité).
+pub fn show_minimap(
+    minimap: Res<Minimap>,
+    hex_config: Res<HexConfig>,
+    world_cache: Option<Res<WorldCache>>,
+    cameras: Query<(&Camera, &GlobalTransform), With<MainCamera>>,
+    mut camera_control: CameraControl,
+    mut contexts: Query<&mut bevy_egui::EguiContext>,
+) {
+    if !minimap.visible || minimap.cells.is_empty() || minimap.bounds.is_empty() {
+        return;
+    }
+    let Ok((camera, camera_transform)) = cameras.single() else {
+        return;
+    };
+    let Ok(mut ctx) = contexts.single_mut() else {
+        return;
+    };
+    let ctx = ctx.get_mut();
+
+    let aspect = minimap.bounds.height() / minimap.bounds.width();
+    let size = egui::vec2(minimap.width, minimap.width * aspect);
+    // Rayon d'une cellule basse résolution, en points du panneau
+    let cell_radius = hex_config.layout.scale.max_element() * minimap.resolution as f32
+        / minimap.bounds.width()
+        * size.x;
+
+    egui::Area::new(egui::Id::new("minimap"))
+        .anchor(egui::Align2::RIGHT_BOTTOM, [-10.0, -10.0])
+        .show(ctx, |ui| {
+            egui::Frame::popup(ui.style()).show(ui, |ui| {
+                let (panel, response) = ui.allocate_exact_size(size, egui::Sense::click_and_drag());
+                let painter = ui.painter_at(panel);
+
+                painter.rect_filled(panel, 0.0, WATER_COLOR);
+                for (center, land) in &minimap.cells {
+                    if *land {
+                        painter.circle_filled(minimap.to_panel(panel, *center), cell_radius, LAND_COLOR);
+                    }
+                }
+
+                if let Some(world_cache) = &world_cache {
+                    let stroke = egui::Stroke::new(1.0, CHUNK_COLOR);
+                    for chunk in &world_cache.chunks {
+                        let corners = hex_config.chunk_layout.hex_corners(chunk.coord.to_hex());
+                        let points = corners
+                            .iter()
+                            .map(|corner| minimap.to_panel(panel, *corner))
+                            .collect();
+                        painter.add(egui::Shape::closed_line(points, stroke));
+                    }
+                }
+
+                if let Some(view) = world_view_rect(camera, camera_transform) {
+                    let view = egui::Rect::from_two_pos(
+                        minimap.to_panel(panel, view.min),
+                        minimap.to_panel(panel, view.max),
+                    );
+                    painter.rect_stroke(
+                        view,
+                        0.0,
+                        egui::Stroke::new(1.5, VIEW_COLOR),
+                        egui::StrokeKind::Middle,
+                    );
+                }
+
+                if (response.clicked() || response.dragged())
+                    && let Some(pointer) = response.interact_pointer_pos()
+                {
+                    camera_control.focus_on(minimap.to_world(panel, pointer), None);
+                }
+            });
+        });
+}
+
+const WATER_COLOR: egui::Color32 = egui::Color32::from_rgb(26, 64, 140);
+const LAND_COLOR: egui::Color32 = egui::Color32::from_rgb(204, 153, 102);
+const CHUNK_COLOR: egui::Color32 = egui::Color32::from_rgb(250, 204, 21);
+const VIEW_COLOR: egui::Color32 = egui::Color32::WHITE;
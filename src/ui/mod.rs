@@ -5,5 +5,7 @@
 pub mod hud;
 pub mod plugin;
 pub mod generation_progress;
+pub mod hex_tooltip;
+pub mod minimap;
 
 pub use plugin::UiPlugin;
\ No newline at end of file
@@ -3,15 +3,33 @@
 // =============================================================================
 
 use bevy::prelude::*;
+use bevy_inspector_egui::bevy_egui;
 
-use super::hud;
+use crate::hex::HexConfig;
+
+use super::{hex_tooltip, hud, minimap};
 
 pub struct UiPlugin;
 
 impl Plugin for UiPlugin {
     fn build(&self, app: &mut App) {
         app
+            .init_resource::<minimap::Minimap>()
             .add_systems(Startup, hud::setup_hud)
-            .add_systems(Update, (hud::update_diagnostic_texts,));
+            .add_systems(
+                Update,
+                (
+                    hud::update_diagnostic_texts,
+                    minimap::toggle_minimap,
+                    minimap::update_minimap.run_if(resource_exists::<HexConfig>),
+                ),
+            )
+            .add_systems(
+                bevy_egui::EguiPrimaryContextPass,
+                (
+                    hex_tooltip::show_hex_tooltip,
+                    minimap::show_minimap.run_if(resource_exists::<HexConfig>),
+                ),
+            );
     }
 }